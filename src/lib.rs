@@ -1,7 +1,8 @@
 mod utils;
 
-use bech32::{Bech32, ToBase32 as _};
+use bech32::{FromBase32 as _, ToBase32 as _};
 use chain::{account, certificate, fee, key, transaction as tx, txbuilder, value};
+use chain_core::mempack::{ReadBuf, Readable as _};
 use chain_core::property::Block as _;
 use chain_core::property::Deserialize as _;
 use chain_core::property::HasFragments as _;
@@ -9,8 +10,11 @@ use chain_core::property::Serialize;
 use chain_crypto as crypto;
 use chain_impl_mockchain as chain;
 use crypto::bech32::Bech32 as _;
+use cryptoxide::blake2b::Blake2b;
+use cryptoxide::curve25519::GeP3;
 use js_sys::Uint8Array;
 use rand_os::OsRng;
+use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::ops::{Add, Sub};
 use std::str::FromStr;
@@ -51,6 +55,34 @@ impl PrivateKey {
         self.0.to_public().into()
     }
 
+    /// Check if this is the private key of the given address spending key
+    /// (the account key for account addresses, the first key for group addresses)
+    pub fn controls_address(&self, address: &Address) -> bool {
+        let public_key = self.0.to_public();
+        match address.0.kind() {
+            chain_addr::Kind::Single(key)
+            | chain_addr::Kind::Account(key)
+            | chain_addr::Kind::Group(key, _) => *key == public_key,
+        }
+    }
+
+    /// Check if the key is obviously unusable: the secret is all zeros, or the public key
+    /// is the identity point of the curve (which any signature would match)
+    pub fn is_weak(&self) -> bool {
+        let secret_is_zero = match self.0 {
+            key::EitherEd25519SecretKey::Normal(ref secret) => {
+                secret.leak_secret().as_ref().iter().all(|b| *b == 0)
+            }
+            key::EitherEd25519SecretKey::Extended(ref secret) => {
+                secret.leak_secret().as_ref().iter().all(|b| *b == 0)
+            }
+        };
+        let public_key = self.0.to_public();
+        let public_key = public_key.as_ref();
+        let public_is_identity = public_key[0] == 1 && public_key[1..].iter().all(|b| *b == 0);
+        secret_is_zero || public_is_identity
+    }
+
     pub fn generate_ed25519() -> Result<PrivateKey, JsValue> {
         OsRng::new()
             .map(crypto::SecretKey::<crypto::Ed25519>::generate)
@@ -67,12 +99,74 @@ impl PrivateKey {
             .map_err(|e| JsValue::from_str(&format!("{}", e)))
     }
 
+    /// Derive a normal ed25519 key from the given seed, the same seed always gives the same key.
+    /// The key is the blake2b-256 hash of the seed. Seeds shorter than 32 bytes are rejected,
+    /// as they can't contain enough entropy for a safe key
+    pub fn from_seed(seed: &[u8]) -> Result<PrivateKey, JsValue> {
+        if seed.len() < 32 {
+            return Err(JsValue::from_str(&format!(
+                "Seed too short. Found {} bytes, expected at least: 32",
+                seed.len()
+            )));
+        }
+        let mut secret = [0u8; 32];
+        Blake2b::blake2b(&mut secret, seed, &[]);
+        crypto::SecretKey::<crypto::Ed25519>::from_binary(&secret)
+            .map(key::EitherEd25519SecretKey::Normal)
+            .map(PrivateKey)
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
+
     pub fn to_bech32(&self) -> String {
         match self.0 {
             key::EitherEd25519SecretKey::Normal(ref secret) => secret.to_bech32_str(),
             key::EitherEd25519SecretKey::Extended(ref secret) => secret.to_bech32_str(),
         }
     }
+
+    /// Sign an arbitrary message, the signature can be checked with `PublicKey.verify`
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        Signature(self.0.sign(&message).coerce())
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        match self.0 {
+            key::EitherEd25519SecretKey::Normal(ref secret) => {
+                secret.leak_secret().as_ref().to_vec()
+            }
+            key::EitherEd25519SecretKey::Extended(ref secret) => {
+                secret.leak_secret().as_ref().to_vec()
+            }
+        }
+    }
+
+    /// Get a normal ed25519 key from its 32 bytes representation
+    pub fn from_normal_bytes(bytes: &[u8]) -> Result<PrivateKey, JsValue> {
+        if bytes.len() != 32 {
+            return Err(JsValue::from_str(&format!(
+                "Invalid private key length. Found {}, expected: 32",
+                bytes.len()
+            )));
+        }
+        crypto::SecretKey::<crypto::Ed25519>::from_binary(bytes)
+            .map(key::EitherEd25519SecretKey::Normal)
+            .map(PrivateKey)
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
+
+    /// Get an extended ed25519 key from its 64 bytes representation
+    pub fn from_extended_bytes(bytes: &[u8]) -> Result<PrivateKey, JsValue> {
+        if bytes.len() != 64 {
+            return Err(JsValue::from_str(&format!(
+                "Invalid private key length. Found {}, expected: 64",
+                bytes.len()
+            )));
+        }
+        crypto::SecretKey::<crypto::Ed25519Extended>::from_binary(bytes)
+            .map(key::EitherEd25519SecretKey::Extended)
+            .map(PrivateKey)
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
 }
 
 /// ED25519 key used as public key
@@ -99,9 +193,45 @@ impl PublicKey {
             .map_err(|_| JsValue::from_str("Malformed public key"))
     }
 
+    /// Get public key from its 32 bytes representation.
+    /// The bytes are validated to be the canonical encoding of a point of the ed25519 curve,
+    /// so malformed keys are rejected here instead of failing later when verifying signatures
+    pub fn from_bytes(bytes: &[u8]) -> Result<PublicKey, JsValue> {
+        if bytes.len() != 32 {
+            return Err(JsValue::from_str(&format!(
+                "Invalid public key length. Found {}, expected: 32",
+                bytes.len()
+            )));
+        }
+        if !is_canonical_point_encoding(bytes) || GeP3::from_bytes_negate_vartime(bytes).is_none() {
+            return Err(JsValue::from_str(
+                "Invalid public key, the bytes are not a valid curve point",
+            ));
+        }
+        crypto::PublicKey::from_binary(bytes)
+            .map(PublicKey)
+            .map_err(|_| JsValue::from_str("Malformed public key"))
+    }
+
     pub fn as_bytes(&self) -> Vec<u8> {
         self.0.as_ref().to_vec()
     }
+
+    pub fn to_bech32(&self) -> String {
+        self.0.to_bech32_str()
+    }
+
+    /// Check that the signature was made with the private key of this public key on the message
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        signature.0.verify_slice(&self.0, message) == crypto::Verification::Success
+    }
+}
+
+/// Check that the y coordinate (the bytes in little endian, without the sign bit of x)
+/// is reduced modulo p = 2^255 - 19
+fn is_canonical_point_encoding(bytes: &[u8]) -> bool {
+    let reduced_high_bytes = bytes[31] & 0x7f != 0x7f || bytes[1..31].iter().any(|b| *b != 0xff);
+    reduced_high_bytes || bytes[0] < 0xed
 }
 
 #[wasm_bindgen]
@@ -127,6 +257,40 @@ impl PublicKeys {
     }
 }
 
+/// ED25519 signature of an arbitrary message
+#[wasm_bindgen]
+pub struct Signature(crypto::Signature<[u8], crypto::Ed25519>);
+
+impl From<crypto::Signature<[u8], crypto::Ed25519>> for Signature {
+    fn from(signature: crypto::Signature<[u8], crypto::Ed25519>) -> Signature {
+        Signature(signature)
+    }
+}
+
+#[wasm_bindgen]
+impl Signature {
+    /// Get signature from its 64 bytes representation
+    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, JsValue> {
+        if bytes.len() != 64 {
+            return Err(JsValue::from_str(&format!(
+                "Invalid signature length. Found {}, expected: 64",
+                bytes.len()
+            )));
+        }
+        crypto::Signature::from_binary(bytes)
+            .map(Signature)
+            .map_err(|_| JsValue::from_str("Malformed signature"))
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.0.as_ref().to_vec()
+    }
+
+    pub fn to_bech32(&self) -> String {
+        self.0.to_bech32_str()
+    }
+}
+
 //-----------------------------//
 //----------Address------------//
 //-----------------------------//
@@ -163,11 +327,78 @@ impl Address {
     /// address.to_string(&#39;ta&#39;)
     /// // ta1sj6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgnj78ende550w5njvwzyr20q6rypdea597uu3jnwfltljddl59cseaq7yn9
     /// ```
-    pub fn to_string(&self, prefix: &str) -> String {
-        format!(
+    /// Fails if the prefix is not a valid bech32 human readable part
+    /// (1 to 83 lowercase ascii characters)
+    pub fn to_string(&self, prefix: &str) -> Result<String, JsValue> {
+        validate_address_prefix(prefix)?;
+        Ok(format!(
             "{}",
             chain_addr::AddressReadable::from_address(prefix, &self.0)
-        )
+        ))
+    }
+
+    /// Get the kind of the address, this can be "Single", "Group" or "Account"
+    pub fn kind(&self) -> String {
+        match self.0.kind() {
+            chain_addr::Kind::Single(_) => "Single".to_string(),
+            chain_addr::Kind::Group(_, _) => "Group".to_string(),
+            chain_addr::Kind::Account(_) => "Account".to_string(),
+        }
+    }
+
+    pub fn discrimination(&self) -> AddressDiscrimination {
+        self.0.discrimination().into()
+    }
+
+    /// Get the spending key of the address: the key of single and account addresses,
+    /// or the first key (not the delegation one) of group addresses.
+    /// All the current address kinds have one, the error is kept for future kinds without keys
+    pub fn payment_key(&self) -> Result<PublicKey, JsValue> {
+        match self.0.kind() {
+            chain_addr::Kind::Single(key)
+            | chain_addr::Kind::Account(key)
+            | chain_addr::Kind::Group(key, _) => Ok(key.clone().into()),
+        }
+    }
+
+    /// Same as `payment_key`
+    pub fn get_spending_key(&self) -> Result<PublicKey, JsValue> {
+        self.payment_key()
+    }
+
+    /// Get the delegation key of a group address, fails for the other kinds
+    pub fn get_delegation_key(&self) -> Result<PublicKey, JsValue> {
+        match self.0.kind() {
+            chain_addr::Kind::Group(_, delegation) => Ok(delegation.clone().into()),
+            chain_addr::Kind::Single(_) | chain_addr::Kind::Account(_) => Err(JsValue::from_str(
+                "Address is not a group address, it has no delegation key",
+            )),
+        }
+    }
+
+    /// Get a copy of the address (same kind and keys) for the given discrimination,
+    /// for example to get the production address of a test address
+    pub fn with_discrimination(&self, discrimination: AddressDiscrimination) -> Address {
+        chain_addr::Address(discrimination.into(), self.0.kind().clone()).into()
+    }
+
+    /// Get the hash of the binary representation of the address, which is a fixed length
+    /// identifier of the address (the same for any bech32 prefix)
+    pub fn to_hash(&self) -> Hash {
+        key::Hash::hash_bytes(&self.as_bytes()).into()
+    }
+
+    /// Get the binary representation of the address
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.0.serialize_as_vec().unwrap()
+    }
+
+    /// Construct Address from its binary representation (see `as_bytes`).
+    /// Fails if the bytes are truncated or the address type is unknown
+    pub fn from_bytes(bytes: &[u8]) -> Result<Address, JsValue> {
+        chain_addr::Address::from_bytes(bytes)
+            .map(Address)
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))
     }
 
     /// Construct a single non-account address from a public key
@@ -212,6 +443,48 @@ impl From<chain_addr::Address> for Address {
     }
 }
 
+fn validate_address_prefix(prefix: &str) -> Result<(), JsValue> {
+    if prefix.is_empty() || prefix.len() > 83 {
+        return Err(JsValue::from_str(&format!(
+            "Invalid prefix length. Found {}, expected: between 1 and 83",
+            prefix.len()
+        )));
+    }
+    match prefix
+        .chars()
+        .find(|c| !c.is_ascii_graphic() || c.is_ascii_uppercase())
+    {
+        Some(c) => Err(JsValue::from_str(&format!(
+            "Invalid character in prefix: {:?}",
+            c
+        ))),
+        None => Ok(()),
+    }
+}
+
+#[wasm_bindgen]
+pub struct Addresses(Vec<Address>);
+
+#[wasm_bindgen]
+impl Addresses {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Addresses {
+        Addresses(vec![])
+    }
+
+    pub fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> Address {
+        self.0[index].clone()
+    }
+
+    pub fn add(&mut self, address: Address) {
+        self.0.push(address);
+    }
+}
+
 /// Allow to differentiate between address in
 /// production and testing setting, so that
 /// one type of address is not used in another setting.
@@ -221,6 +494,7 @@ impl From<chain_addr::Address> for Address {
 /// let address = Address::single_from_public_key(public_key, discriminant);
 /// ```
 #[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AddressDiscrimination {
     Production,
     Test,
@@ -235,14 +509,33 @@ impl Into<chain_addr::Discrimination> for AddressDiscrimination {
     }
 }
 
+impl From<chain_addr::Discrimination> for AddressDiscrimination {
+    fn from(discrimination: chain_addr::Discrimination) -> AddressDiscrimination {
+        match discrimination {
+            chain_addr::Discrimination::Production => AddressDiscrimination::Production,
+            chain_addr::Discrimination::Test => AddressDiscrimination::Test,
+        }
+    }
+}
+
 //-----------------------------------//
 //-------- Transaction --------------//
 //-----------------------------------//
 
 /// Type representing a unsigned transaction
+///
+/// The transaction can't be modified, so its id is computed only once (see `id`).
+/// The spending counters given with the inputs to the `TransactionBuilder` are kept
+/// (one per input, in the same order), so they can be read back when signing
 #[wasm_bindgen]
-pub struct Transaction(EitherTransaction);
+#[derive(Clone)]
+pub struct Transaction(
+    EitherTransaction,
+    RefCell<Option<tx::TransactionSignDataHash>>,
+    Vec<Option<account::SpendingCounter>>,
+);
 
+#[derive(Clone)]
 enum EitherTransaction {
     TransactionWithoutCertificate(tx::Transaction<chain_addr::Address, tx::NoExtra>),
     TransactionWithCertificate(tx::Transaction<chain_addr::Address, certificate::Certificate>),
@@ -272,17 +565,64 @@ impl EitherTransaction {
         }
         .to_vec()
     }
+
+    fn implied_fee(&self) -> Result<value::Value, value::ValueError> {
+        match &self {
+            EitherTransaction::TransactionWithoutCertificate(tx) => implied_fee(tx),
+            EitherTransaction::TransactionWithCertificate(tx) => implied_fee(tx),
+        }
+    }
+}
+
+fn implied_fee<Extra>(
+    tx: &tx::Transaction<chain_addr::Address, Extra>,
+) -> Result<value::Value, value::ValueError> {
+    let total_input = value::Value::sum(tx.inputs.iter().map(|input| input.value))?;
+    let total_output = value::Value::sum(tx.outputs.iter().map(|output| output.value))?;
+    total_input.sub(total_output)
+}
+
+/// Error for the failed value computations, overflows are reported as "Value overflow" so they
+/// can be told apart from the other errors
+fn value_error(error: value::ValueError) -> JsValue {
+    match error {
+        value::ValueError::Overflow => JsValue::from_str("Value overflow"),
+        error => JsValue::from_str(&format!("{}", error)),
+    }
+}
+
+/// Sort the inputs and outputs by their serialized representation,
+/// so the order in which they were added doesn't matter
+fn sort_canonically<Extra>(tx: &mut tx::Transaction<chain_addr::Address, Extra>) {
+    tx.inputs
+        .sort_by_cached_key(|input| input.serialize_as_vec().unwrap());
+    tx.outputs.sort_by_cached_key(|output| {
+        (
+            output.address.serialize_as_vec().unwrap(),
+            *output.value.as_ref(),
+        )
+    });
 }
 
 impl From<tx::Transaction<chain_addr::Address, tx::NoExtra>> for Transaction {
     fn from(tx: tx::Transaction<chain_addr::Address, tx::NoExtra>) -> Self {
-        Transaction(EitherTransaction::TransactionWithoutCertificate(tx))
+        let spending_counters = vec![None; tx.inputs.len()];
+        Transaction(
+            EitherTransaction::TransactionWithoutCertificate(tx),
+            RefCell::new(None),
+            spending_counters,
+        )
     }
 }
 
 impl From<tx::Transaction<chain_addr::Address, certificate::Certificate>> for Transaction {
     fn from(tx: tx::Transaction<chain_addr::Address, certificate::Certificate>) -> Self {
-        Transaction(EitherTransaction::TransactionWithCertificate(tx))
+        let spending_counters = vec![None; tx.inputs.len()];
+        Transaction(
+            EitherTransaction::TransactionWithCertificate(tx),
+            RefCell::new(None),
+            spending_counters,
+        )
     }
 }
 
@@ -313,20 +653,69 @@ macro_rules! impl_collection {
 impl_collection!(Outputs, Output);
 impl_collection!(Inputs, Input);
 impl_collection!(Fragments, Fragment);
+impl_collection!(TransactionBuilders, TransactionBuilder);
+
+#[wasm_bindgen]
+impl Inputs {
+    /// Remove the repeated inputs, keeping the first occurrence of each one
+    pub fn dedup(&mut self) {
+        let mut unique: Vec<Input> = Vec::with_capacity(self.0.len());
+        for input in self.0.drain(..) {
+            if !unique.iter().any(|seen| seen.equals(&input)) {
+                unique.push(input);
+            }
+        }
+        self.0 = unique;
+    }
+}
+
+#[wasm_bindgen]
+impl Fragments {
+    /// Serialize the fragments with the layout of the contents of a block: the fragments
+    /// one after the other, each one prefixed by its size (as done by `Fragment.as_bytes`)
+    pub fn as_block_body_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        let mut bytes = Vec::new();
+        for fragment in self.0.iter() {
+            bytes.extend(fragment.as_bytes()?);
+        }
+        Ok(bytes)
+    }
+}
 
 #[wasm_bindgen]
 impl Transaction {
-    /// Get the transaction id, needed to compute its signature
+    /// Create a transaction without inputs, outputs nor certificate
+    pub fn empty() -> Transaction {
+        tx::Transaction {
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            extra: tx::NoExtra,
+        }
+        .into()
+    }
+
+    /// Get the transaction id, needed to compute its signature.
+    /// The id is computed on the first call and kept for the next ones
     pub fn id(&self) -> TransactionSignDataHash {
-        self.0.id()
+        (*self.1.borrow_mut().get_or_insert_with(|| self.0.id().0)).into()
+    }
+
+    /// Get the serialized transaction (without witnesses), these are the bytes hashed by `id`
+    pub fn sign_data_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        match &self.0 {
+            EitherTransaction::TransactionWithoutCertificate(tx) => tx.serialize_as_vec(),
+            EitherTransaction::TransactionWithCertificate(tx) => tx.serialize_as_vec(),
+        }
+        .map_err(|error| JsValue::from_str(&format!("{}", error)))
     }
 
     /// Get collection of the inputs in the transaction (this allocates new copies of all the values)
     pub fn inputs(&self) -> Inputs {
         self.0
             .inputs()
-            .iter()
-            .map(|input| Input(input.clone()))
+            .into_iter()
+            .zip(self.2.iter())
+            .map(|(input, spending_counter)| Input(input, *spending_counter))
             .collect::<Vec<Input>>()
             .into()
     }
@@ -340,6 +729,184 @@ impl Transaction {
             .collect::<Vec<Output>>()
             .into()
     }
+
+    /// Get the number of certificates in the transaction, which is at most one
+    pub fn certificate_count(&self) -> usize {
+        match &self.0 {
+            EitherTransaction::TransactionWithoutCertificate(_) => 0,
+            EitherTransaction::TransactionWithCertificate(_) => 1,
+        }
+    }
+
+    /// Get the fee paid by the transaction, this is the difference between the sum of the inputs
+    /// and the sum of the outputs. Fails if the outputs exceed the inputs
+    pub fn implied_fee(&self) -> Result<Value, JsValue> {
+        self.0.implied_fee().map_err(value_error).map(Value)
+    }
+
+    /// Get the sum of the values of the inputs, fails with "Value overflow" if it doesn't fit in a u64
+    pub fn total_input_value(&self) -> Result<Value, JsValue> {
+        value::Value::sum(self.0.inputs().iter().map(|input| input.value))
+            .map_err(value_error)
+            .map(Value)
+    }
+
+    /// Get the sum of the values of the outputs, fails with "Value overflow" if it doesn't fit in a u64
+    pub fn total_output_value(&self) -> Result<Value, JsValue> {
+        value::Value::sum(self.0.outputs().iter().map(|output| output.value))
+            .map_err(value_error)
+            .map(Value)
+    }
+
+    /// Get the fee (see `implied_fee`) relative to the total value of the outputs, in basis
+    /// points (hundredths of a percent), rounded down. Fails if the outputs have no value
+    pub fn fee_ratio_basis_points(&self) -> Result<u32, JsValue> {
+        let fee = self.implied_fee()?;
+        let total_output = self.total_output_value()?.0;
+        if total_output.0 == 0 {
+            return Err(JsValue::from_str(
+                "The fee ratio is not defined for a transaction without output value",
+            ));
+        }
+        let basis_points = u128::from(*fee.as_ref()) * 10_000 / u128::from(total_output.0);
+        u32::try_from(basis_points).map_err(|_| JsValue::from_str("Fee ratio out of range"))
+    }
+
+    /// Get how much the fee of this transaction computed with the given algorithm exceeds a
+    /// previous estimate, for example the one made before finalizing, as finalizing may add
+    /// a change output. This is zero if the fee is not above the estimate
+    pub fn fee_overpayment(&self, estimated: &Value, fee: &Fee) -> Result<Value, JsValue> {
+        let actual = fee
+            .calculate(self.clone())
+            .ok_or_else(|| JsValue::from_str("Fee out of range"))?;
+        Ok(actual
+            .0
+            .sub(estimated.0)
+            .map(Value)
+            .unwrap_or_else(|_| Value::from(0)))
+    }
+
+    /// Get the index of the first output going to one of the given addresses, which is
+    /// probably the change output when the addresses are the ones owned by the wallet
+    pub fn likely_change_output(&self, my_addresses: Addresses) -> Option<usize> {
+        self.0.outputs().iter().position(|output| {
+            my_addresses
+                .0
+                .iter()
+                .any(|address| address.0 == output.address)
+        })
+    }
+
+    /// Check if all the outputs go to the given addresses, so when they are the ones
+    /// owned by the wallet the transaction only moves funds between them (a consolidation).
+    /// A transaction without outputs is not a self transfer
+    pub fn is_self_transfer(&self, my_addresses: Addresses) -> bool {
+        let outputs = self.0.outputs();
+        !outputs.is_empty()
+            && outputs.iter().all(|output| {
+                my_addresses
+                    .0
+                    .iter()
+                    .any(|address| address.0 == output.address)
+            })
+    }
+
+    /// Serialize the transaction with its inputs and outputs sorted, so two transactions
+    /// with the same content give the same bytes regardless of the order used to build them.
+    /// This is meant for comparing transactions, the bytes are not the ones signed by the witnesses
+    pub fn canonical_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        match &self.0 {
+            EitherTransaction::TransactionWithoutCertificate(tx) => {
+                let mut tx = tx.clone();
+                sort_canonically(&mut tx);
+                tx.serialize_as_vec()
+            }
+            EitherTransaction::TransactionWithCertificate(tx) => {
+                let mut tx = tx.clone();
+                sort_canonically(&mut tx);
+                tx.serialize_as_vec()
+            }
+        }
+        .map_err(|error| JsValue::from_str(&format!("{}", error)))
+    }
+
+    /// Check if both transactions have the same canonical bytes (see `canonical_bytes`)
+    pub fn canonical_equals(&self, other: &Transaction) -> bool {
+        match (self.canonical_bytes(), other.canonical_bytes()) {
+            (Ok(bytes), Ok(other_bytes)) => bytes == other_bytes,
+            _ => false,
+        }
+    }
+
+    /// Get the UtxoPointer of every utxo input of the transaction, skipping the account inputs
+    pub fn spent_utxos(&self) -> Vec<JsValue> {
+        self.0
+            .inputs()
+            .iter()
+            .filter_map(|input| match input.to_enum() {
+                tx::InputEnum::UtxoInput(utxo_pointer) => {
+                    Some(UtxoPointer::from(utxo_pointer).into())
+                }
+                tx::InputEnum::AccountInput(_, _) => None,
+            })
+            .collect()
+    }
+
+    /// Get the distinct public keys of the account inputs and of the addresses of the
+    /// outputs (both keys for group addresses). The utxo inputs don't reference any key
+    pub fn referenced_public_keys(&self) -> PublicKeys {
+        let mut keys: Vec<crypto::PublicKey<crypto::Ed25519>> = Vec::new();
+        for input in self.0.inputs() {
+            if let tx::InputEnum::AccountInput(account, _) = input.to_enum() {
+                if let Some(key) = account.to_single_account() {
+                    keys.push(key.into());
+                }
+            }
+        }
+        for output in self.0.outputs() {
+            match output.address.kind() {
+                chain_addr::Kind::Single(key) | chain_addr::Kind::Account(key) => {
+                    keys.push(key.clone())
+                }
+                chain_addr::Kind::Group(key, delegation) => {
+                    keys.push(key.clone());
+                    keys.push(delegation.clone());
+                }
+            }
+        }
+        let mut unique: Vec<PublicKey> = Vec::with_capacity(keys.len());
+        for key in keys {
+            if !unique.iter().any(|seen| seen.0 == key) {
+                unique.push(key.into());
+            }
+        }
+        PublicKeys(unique)
+    }
+
+    /// Check if any of the inputs of the transaction spends from the given account
+    pub fn spends_from_account(&self, account: &Account) -> bool {
+        self.0.inputs().iter().any(|input| match input.to_enum() {
+            tx::InputEnum::AccountInput(identifier, _) => identifier == account.0,
+            tx::InputEnum::UtxoInput(_) => false,
+        })
+    }
+
+    /// Check if any of the outputs of the transaction goes to the given address
+    pub fn pays_to(&self, address: &Address) -> bool {
+        self.0
+            .outputs()
+            .iter()
+            .any(|output| output.address == address.0)
+    }
+
+    /// Same as `pays_to`, but only the address kind (type and keys) is compared, so
+    /// the address discrimination (production or test) is ignored
+    pub fn pays_to_ignoring_discrimination(&self, address: &Address) -> bool {
+        self.0
+            .outputs()
+            .iter()
+            .any(|output| output.address.kind() == address.0.kind())
+    }
 }
 
 //-----------------------------------//
@@ -380,8 +947,14 @@ impl Transaction {
 /// );
 /// ```
 #[wasm_bindgen]
-pub struct TransactionBuilder(EitherTransactionBuilder);
+#[derive(Clone)]
+pub struct TransactionBuilder(
+    EitherTransactionBuilder,
+    // the spending counters of the inputs (see `Input.spending_counter`), in the same order
+    Vec<Option<account::SpendingCounter>>,
+);
 
+#[derive(Clone)]
 enum EitherTransactionBuilder {
     TransactionBuilderNoExtra(txbuilder::TransactionBuilder<chain_addr::Address, tx::NoExtra>),
     TransactionBuilderCertificate(
@@ -391,7 +964,11 @@ enum EitherTransactionBuilder {
 
 impl From<txbuilder::TransactionBuilder<chain_addr::Address, tx::NoExtra>> for TransactionBuilder {
     fn from(builder: txbuilder::TransactionBuilder<chain_addr::Address, tx::NoExtra>) -> Self {
-        TransactionBuilder(EitherTransactionBuilder::TransactionBuilderNoExtra(builder))
+        let spending_counters = vec![None; builder.tx.inputs.len()];
+        TransactionBuilder(
+            EitherTransactionBuilder::TransactionBuilderNoExtra(builder),
+            spending_counters,
+        )
     }
 }
 
@@ -401,9 +978,11 @@ impl From<txbuilder::TransactionBuilder<chain_addr::Address, certificate::Certif
     fn from(
         builder: txbuilder::TransactionBuilder<chain_addr::Address, certificate::Certificate>,
     ) -> Self {
-        TransactionBuilder(EitherTransactionBuilder::TransactionBuilderCertificate(
-            builder,
-        ))
+        let spending_counters = vec![None; builder.tx.inputs.len()];
+        TransactionBuilder(
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder),
+            spending_counters,
+        )
     }
 }
 
@@ -414,6 +993,51 @@ impl TransactionBuilder {
         txbuilder::TransactionBuilder::new().into()
     }
 
+    /// Create a builder with the inputs, outputs and certificate of an existing transaction
+    pub fn from_transaction(tx: Transaction) -> Self {
+        let builder: TransactionBuilder = match tx.0 {
+            EitherTransaction::TransactionWithoutCertificate(tx) => {
+                txbuilder::TransactionBuilder::from(tx).into()
+            }
+            EitherTransaction::TransactionWithCertificate(tx) => {
+                txbuilder::TransactionBuilder::from(tx).into()
+            }
+        };
+        builder.with_spending_counters(tx.2)
+    }
+
+    /// Create a builder from an existing transaction paying `additional_fee` more in fees,
+    /// the extra fee is taken from the (first) output going to `change_address`.
+    /// The change output is removed if its value becomes zero
+    ///
+    /// Example
+    ///
+    /// ```javascript
+    /// const txbuilder = TransactionBuilder.from_transaction_bump_fee(
+    ///   signedTx.transaction(),
+    ///   Value.from_str('10'),
+    ///   changeAddress
+    /// );
+    /// const bumpedTx = txbuilder.unchecked_finalize();
+    /// ```
+    pub fn from_transaction_bump_fee(
+        tx: Transaction,
+        additional_fee: Value,
+        change_address: Address,
+    ) -> Result<TransactionBuilder, JsValue> {
+        let builder: TransactionBuilder = match tx.0 {
+            EitherTransaction::TransactionWithoutCertificate(mut tx) => {
+                bump_fee(&mut tx.outputs, additional_fee.0, &change_address.0)?;
+                txbuilder::TransactionBuilder::from(tx).into()
+            }
+            EitherTransaction::TransactionWithCertificate(mut tx) => {
+                bump_fee(&mut tx.outputs, additional_fee.0, &change_address.0)?;
+                txbuilder::TransactionBuilder::from(tx).into()
+            }
+        };
+        Ok(builder.with_spending_counters(tx.2))
+    }
+
     /// Add certificate to the transaction if there isn't one already
     /// Example
     /// ```javascript
@@ -443,7 +1067,25 @@ impl TransactionBuilder {
         Ok(())
     }
 
-    /// Add input to the transaction
+    /// Remove the certificate set with `set_certificate`, keeping the inputs and outputs.
+    /// Does nothing if there is no certificate
+    #[wasm_bindgen]
+    pub fn clear_certificate(&mut self) {
+        let tx = match &self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(_) => return,
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder) => tx::Transaction {
+                inputs: builder.tx.inputs.clone(),
+                outputs: builder.tx.outputs.clone(),
+                extra: tx::NoExtra,
+            },
+        };
+        self.0 = EitherTransactionBuilder::TransactionBuilderNoExtra(
+            txbuilder::TransactionBuilder::from(tx),
+        );
+    }
+
+    /// Add input to the transaction, its spending counter (if any) is kept with it,
+    /// see `Input.spending_counter` and `TransactionFinalizer.spending_counter`
     #[wasm_bindgen]
     pub fn add_input(&mut self, input: Input) {
         match &mut self.0 {
@@ -454,27 +1096,90 @@ impl TransactionBuilder {
                 builder.add_input(&input.0)
             }
         }
+        self.1.push(input.1);
     }
 
-    /// Add output to the transaction
+    /// Remove the input at the given position, fails if there is no input at that position
     #[wasm_bindgen]
-    pub fn add_output(&mut self, address: Address, value: Value) {
-        match &mut self.0 {
-            EitherTransactionBuilder::TransactionBuilderNoExtra(ref mut builder) => {
-                builder.add_output(address.0, value.0)
-            }
-            EitherTransactionBuilder::TransactionBuilderCertificate(ref mut builder) => {
-                builder.add_output(address.0, value.0)
+    pub fn remove_input(&mut self, index: usize) -> Result<(), JsValue> {
+        let inputs = match &mut self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => &mut builder.tx.inputs,
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder) => {
+                &mut builder.tx.inputs
             }
+        };
+        if index >= inputs.len() {
+            return Err(JsValue::from_str(&format!(
+                "Index out of bounds. Found {}, but there are {} inputs",
+                index,
+                inputs.len()
+            )));
         }
+        inputs.remove(index);
+        self.1.remove(index);
+        Ok(())
     }
 
-    /// Estimate fee with the currently added inputs, outputs and certificate based on the given algorithm
+    /// Remove the output at the given position, fails if there is no output at that position
     #[wasm_bindgen]
-    pub fn estimate_fee(&self, fee: &Fee) -> Result<Value, JsValue> {
-        let fee_algorithm = match fee.0 {
-            FeeVariant::Linear(fee_algorithm) => fee_algorithm,
-        };
+    pub fn remove_output(&mut self, index: usize) -> Result<(), JsValue> {
+        let outputs = match &mut self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => &mut builder.tx.outputs,
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder) => {
+                &mut builder.tx.outputs
+            }
+        };
+        if index >= outputs.len() {
+            return Err(JsValue::from_str(&format!(
+                "Index out of bounds. Found {}, but there are {} outputs",
+                index,
+                outputs.len()
+            )));
+        }
+        outputs.remove(index);
+        Ok(())
+    }
+
+    /// Get a copy of the inputs added so far, with their spending counters
+    #[wasm_bindgen]
+    pub fn get_inputs(&self) -> Inputs {
+        self.inputs()
+            .iter()
+            .zip(self.1.iter())
+            .map(|(input, spending_counter)| Input(input.clone(), *spending_counter))
+            .collect::<Vec<Input>>()
+            .into()
+    }
+
+    /// Get a copy of the outputs added so far
+    #[wasm_bindgen]
+    pub fn get_outputs(&self) -> Outputs {
+        self.outputs()
+            .iter()
+            .map(|output| Output(output.clone()))
+            .collect::<Vec<Output>>()
+            .into()
+    }
+
+    /// Add output to the transaction
+    #[wasm_bindgen]
+    pub fn add_output(&mut self, address: Address, value: Value) {
+        match &mut self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(ref mut builder) => {
+                builder.add_output(address.0, value.0)
+            }
+            EitherTransactionBuilder::TransactionBuilderCertificate(ref mut builder) => {
+                builder.add_output(address.0, value.0)
+            }
+        }
+    }
+
+    /// Estimate fee with the currently added inputs, outputs and certificate based on the given algorithm
+    #[wasm_bindgen]
+    pub fn estimate_fee(&self, fee: &Fee) -> Result<Value, JsValue> {
+        let fee_algorithm = match fee.0 {
+            FeeVariant::Linear(fee_algorithm) => fee_algorithm,
+        };
         match &self.0 {
             EitherTransactionBuilder::TransactionBuilderNoExtra(ref builder) => {
                 builder.estimate_fee(fee_algorithm)
@@ -504,6 +1209,19 @@ impl TransactionBuilder {
         .map(|balance| balance.into())
     }
 
+    /// Get the value that the inputs still to be added have to provide to pay the outputs and
+    /// the fee, including the fee of one more input. This is zero if the transaction is already funded
+    #[wasm_bindgen]
+    pub fn shortfall(&self, fee: &Fee) -> Result<Value, JsValue> {
+        match self.get_balance(fee)?.0 {
+            tx::Balance::Negative(missing) => missing
+                .add(fee.dust_threshold().0)
+                .map(Value)
+                .map_err(|e| JsValue::from_str(&format!("{}", e))),
+            tx::Balance::Zero | tx::Balance::Positive(_) => Ok(Value::from(0)),
+        }
+    }
+
     #[wasm_bindgen]
     pub fn get_balance_without_fee(&self) -> Result<Balance, JsValue> {
         match &self.0 {
@@ -521,10 +1239,11 @@ impl TransactionBuilder {
     /// Get the Transaction with the current inputs and outputs without computing the fees nor adding a change address
     #[wasm_bindgen]
     pub fn unchecked_finalize(self) -> Transaction {
-        match self.0 {
+        let transaction: Transaction = match self.0 {
             EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => builder.tx.into(),
             EitherTransactionBuilder::TransactionBuilderCertificate(builder) => builder.tx.into(),
-        }
+        };
+        transaction.with_spending_counters(self.1)
     }
 
     /// Finalize the transaction by adding the change Address output
@@ -544,22 +1263,196 @@ impl TransactionBuilder {
     /// );
     /// ```
     #[wasm_bindgen]
-    pub fn finalize(self, fee: &Fee, output_policy: OutputPolicy) -> Result<Transaction, JsValue> {
+    pub fn finalize(
+        mut self,
+        fee: &Fee,
+        output_policy: OutputPolicy,
+    ) -> Result<Transaction, JsValue> {
         let fee_algorithm = match fee.0 {
             FeeVariant::Linear(fee_algorithm) => fee_algorithm,
         };
+        let minimum_fee = self.estimate_fee(fee)?;
+        let output_policy = self.resolve_output_policy(output_policy, minimum_fee.0)?;
 
+        let spending_counters = self.1;
         match self.0 {
             EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => builder
-                .finalize(fee_algorithm, output_policy.0)
-                .map(|(_, tx)| tx.into()),
+                .finalize(fee_algorithm, output_policy)
+                .map(|(_, tx)| Transaction::from(tx)),
             EitherTransactionBuilder::TransactionBuilderCertificate(builder) => builder
-                .finalize(fee_algorithm, output_policy.0)
-                .map(|(_, tx)| tx.into()),
+                .finalize(fee_algorithm, output_policy)
+                .map(|(_, tx)| Transaction::from(tx)),
         }
+        .map(|transaction| transaction.with_spending_counters(spending_counters))
         .map_err(|e| JsValue::from_str(&format!("{}", e)))
     }
 
+    /// Finalize the transaction paying exactly the given fee instead of the minimum computed by the
+    /// fee algorithm, the excess is handled by the output policy as in `finalize`.
+    /// This fails if `exact_fee` is below the minimum fee of the transaction
+    ///
+    /// Example
+    ///
+    /// ```javascript
+    /// const finalizedTx = txbuilder.finalize_with_fee(
+    ///   feeAlgorithm,
+    ///   Value.from_str('100'),
+    ///   OutputPolicy.one(changeAddress)
+    /// );
+    /// ```
+    #[wasm_bindgen]
+    pub fn finalize_with_fee(
+        mut self,
+        fee: &Fee,
+        exact_fee: Value,
+        output_policy: OutputPolicy,
+    ) -> Result<Transaction, JsValue> {
+        let output_policy = self.resolve_output_policy(output_policy, exact_fee.0)?;
+        // the change output added by the policy is paid by the fee too
+        let minimum = match output_policy {
+            txbuilder::OutputPolicy::One(_) => {
+                let certificates = match &self.0 {
+                    EitherTransactionBuilder::TransactionBuilderNoExtra(_) => 0,
+                    EitherTransactionBuilder::TransactionBuilderCertificate(_) => 1,
+                };
+                fee.estimate(self.inputs().len(), self.outputs_count() + 1, certificates)?
+            }
+            _ => self.estimate_fee(fee)?,
+        };
+        if exact_fee.0 < minimum.0 {
+            return Err(JsValue::from_str(&format!(
+                "Fee {} is below the minimum fee {}",
+                exact_fee.0, minimum.0
+            )));
+        }
+
+        let fee_algorithm = match fee.0 {
+            FeeVariant::Linear(fee_algorithm) => ExactFee {
+                minimum: fee_algorithm,
+                fee: exact_fee.0,
+            },
+        };
+
+        let spending_counters = self.1;
+        match self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => builder
+                .finalize(fee_algorithm, output_policy)
+                .map(|(_, tx)| Transaction::from(tx)),
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder) => builder
+                .finalize(fee_algorithm, output_policy)
+                .map(|(_, tx)| Transaction::from(tx)),
+        }
+        .map(|transaction| transaction.with_spending_counters(spending_counters))
+        .map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
+
+    /// Same as `finalize`, but also returns the index of the change output added by the
+    /// output policy, so the change can be told apart from the payments even when the
+    /// change address is also used in a payment
+    ///
+    /// Example
+    ///
+    /// ```javascript
+    /// const finalized = txbuilder.finalize_with_change_index(
+    ///   feeAlgorithm,
+    ///   OutputPolicy.one(changeAddress)
+    /// );
+    /// const changeIndex = finalized.change_index(); // undefined if no change was added
+    /// const finalizedTx = finalized.transaction();
+    /// ```
+    #[wasm_bindgen]
+    pub fn finalize_with_change_index(
+        self,
+        fee: &Fee,
+        output_policy: OutputPolicy,
+    ) -> Result<FinalizedTransaction, JsValue> {
+        let outputs_before = self.outputs_count();
+        let transaction = self.finalize(fee, output_policy)?;
+        // the change output is always appended after the existing ones
+        let change_index = if transaction.0.outputs().len() > outputs_before {
+            Some(outputs_before)
+        } else {
+            None
+        };
+        Ok(FinalizedTransaction {
+            transaction,
+            change_index,
+        })
+    }
+
+    /// Split the outputs in groups of at most `max_outputs`, each one in a new builder.
+    /// The inputs are distributed in the order they were added: each builder takes inputs until
+    /// it has enough to pay its outputs and the fee computed with the given algorithm, and
+    /// the last one takes all the remaining inputs. The certificate, if any, goes to the first builder.
+    /// Fails if the inputs are not enough to fund all the groups
+    ///
+    /// Example
+    ///
+    /// ```javascript
+    /// const builders = txbuilder.split_outputs(10, feeAlgorithm);
+    /// for (let i = 0; i < builders.size(); i++) {
+    ///   const finalizedTx = builders.get(i).finalize(feeAlgorithm, OutputPolicy.one(changeAddress));
+    /// }
+    /// ```
+    #[wasm_bindgen]
+    pub fn split_outputs(
+        self,
+        max_outputs: usize,
+        fee: &Fee,
+    ) -> Result<TransactionBuilders, JsValue> {
+        if max_outputs == 0 {
+            return Err(JsValue::from_str("max_outputs must be greater than zero"));
+        }
+        let (inputs, outputs, mut certificate) = match self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => {
+                (builder.tx.inputs, builder.tx.outputs, None)
+            }
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder) => (
+                builder.tx.inputs,
+                builder.tx.outputs,
+                Some(builder.tx.extra),
+            ),
+        };
+
+        if outputs.is_empty() {
+            return Err(JsValue::from_str("There are no outputs to split"));
+        }
+
+        let mut inputs = inputs
+            .into_iter()
+            .zip(self.1.into_iter())
+            .map(|(input, spending_counter)| Input(input, spending_counter));
+        let groups = outputs.chunks(max_outputs).count();
+        let mut builders = Vec::with_capacity(groups);
+        for (index, group) in outputs.chunks(max_outputs).enumerate() {
+            let mut builder = TransactionBuilder::new();
+            if let Some(certificate) = certificate.take() {
+                builder.set_certificate(certificate.into())?;
+            }
+            for output in group {
+                builder.add_output(output.address.clone().into(), output.value.into());
+            }
+            if index + 1 == groups {
+                inputs.by_ref().for_each(|input| builder.add_input(input));
+            } else {
+                while builder.is_underfunded(fee)? {
+                    match inputs.next() {
+                        Some(input) => builder.add_input(input),
+                        None => break,
+                    }
+                }
+            }
+            if builder.is_underfunded(fee)? {
+                return Err(JsValue::from_str(&format!(
+                    "Not enough inputs to fund the outputs group {}",
+                    index
+                )));
+            }
+            builders.push(builder);
+        }
+        Ok(builders.into())
+    }
+
     /// Get the current Transaction id, this will change when adding input, outputs and certificates
     #[wasm_bindgen]
     pub fn get_txid(&self) -> TransactionSignDataHash {
@@ -574,15 +1467,170 @@ impl TransactionBuilder {
     }
 }
 
-/// Helper to add change addresses when finalizing a transaction, there are currently two options
+fn bump_fee(
+    outputs: &mut Vec<tx::Output<chain_addr::Address>>,
+    additional_fee: value::Value,
+    change_address: &chain_addr::Address,
+) -> Result<(), JsValue> {
+    let index = outputs
+        .iter()
+        .position(|output| &output.address == change_address)
+        .ok_or_else(|| JsValue::from_str("There is no output to the change address"))?;
+    let change = outputs[index]
+        .value
+        .sub(additional_fee)
+        .map_err(|_| JsValue::from_str("The change output can't pay the additional fee"))?;
+    if change.0 == 0 {
+        outputs.remove(index);
+    } else {
+        outputs[index].value = change;
+    }
+    Ok(())
+}
+
+impl TransactionBuilder {
+    fn with_spending_counters(
+        mut self,
+        spending_counters: Vec<Option<account::SpendingCounter>>,
+    ) -> Self {
+        self.1 = spending_counters;
+        self
+    }
+
+    fn inputs(&self) -> &Vec<tx::Input> {
+        match &self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => &builder.tx.inputs,
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder) => &builder.tx.inputs,
+        }
+    }
+
+    fn outputs(&self) -> &Vec<tx::Output<chain_addr::Address>> {
+        match &self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => &builder.tx.outputs,
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder) => &builder.tx.outputs,
+        }
+    }
+
+    fn outputs_count(&self) -> usize {
+        self.outputs().len()
+    }
+
+    /// Get the address of the given key with the same kind as the largest output (the first
+    /// one if there are several), a group address delegates to `delegation`, see
+    /// `OutputPolicy.change_like_output`
+    fn change_like_output_address(
+        &self,
+        key: crypto::PublicKey<crypto::Ed25519>,
+        delegation: crypto::PublicKey<crypto::Ed25519>,
+        discrimination: chain_addr::Discrimination,
+    ) -> chain_addr::Address {
+        let largest = self.outputs().iter().fold(
+            None,
+            |largest: Option<&tx::Output<_>>, output| match largest {
+                Some(largest) if largest.value.0 >= output.value.0 => Some(largest),
+                _ => Some(output),
+            },
+        );
+        let kind = match largest.map(|output| output.address.kind()) {
+            Some(chain_addr::Kind::Account(_)) => chain_addr::Kind::Account(key),
+            Some(chain_addr::Kind::Group(_, _)) => chain_addr::Kind::Group(key, delegation),
+            Some(chain_addr::Kind::Single(_)) | None => chain_addr::Kind::Single(key),
+        };
+        chain_addr::Address(discrimination, kind)
+    }
+
+    fn is_underfunded(&self, fee: &Fee) -> Result<bool, JsValue> {
+        match self.get_balance(fee)?.0 {
+            tx::Balance::Negative(_) => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    /// Get the chain output policy to use when finalizing. For `merge_into_existing` the
+    /// change (the excess after paying `fee`) is added here to the existing output, and then
+    /// there is nothing left to do with the excess
+    fn resolve_output_policy(
+        &mut self,
+        output_policy: OutputPolicy,
+        fee: value::Value,
+    ) -> Result<txbuilder::OutputPolicy, JsValue> {
+        let address = match output_policy.0 {
+            OutputPolicyVariant::Chain(policy) => return Ok(policy),
+            OutputPolicyVariant::ChangeLikeOutput(key, delegation, discrimination) => {
+                return Ok(txbuilder::OutputPolicy::One(
+                    self.change_like_output_address(key, delegation, discrimination),
+                ))
+            }
+            OutputPolicyVariant::MergeIntoExisting(address) => address,
+        };
+        let balance = self.get_balance_without_fee()?;
+        let outputs = match &mut self.0 {
+            EitherTransactionBuilder::TransactionBuilderNoExtra(builder) => &mut builder.tx.outputs,
+            EitherTransactionBuilder::TransactionBuilderCertificate(builder) => {
+                &mut builder.tx.outputs
+            }
+        };
+        let output = match outputs.iter_mut().find(|output| output.address == address) {
+            Some(output) => output,
+            None => return Ok(txbuilder::OutputPolicy::One(address)),
+        };
+        // the linear fee doesn't depend on the output values, so the fee
+        // is the same after increasing the existing output
+        if let tx::Balance::Positive(excess) = balance.0 {
+            if let Ok(change) = excess.sub(fee) {
+                output.value = output
+                    .value
+                    .add(change)
+                    .map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+            }
+        }
+        Ok(txbuilder::OutputPolicy::Forget)
+    }
+}
+
+/// Result of `TransactionBuilder::finalize_with_change_index`
+#[wasm_bindgen]
+pub struct FinalizedTransaction {
+    transaction: Transaction,
+    change_index: Option<usize>,
+}
+
+#[wasm_bindgen]
+impl FinalizedTransaction {
+    /// Get the finalized transaction
+    pub fn transaction(&self) -> Transaction {
+        self.transaction.clone()
+    }
+
+    /// Get the index of the change output added when finalizing. There is none when the
+    /// policy is `forget`, when there was no excess, or when the excess was merged into an
+    /// existing output with `merge_into_existing`
+    pub fn change_index(&self) -> Option<usize> {
+        self.change_index
+    }
+}
+
+/// Helper to add change addresses when finalizing a transaction, there are currently four options
 /// * forget: use all the excess money as fee
 /// * one: send all the excess money to the given address
+/// * merge_into_existing: add the excess money to the output going to the given address
+/// * change_like_output: send all the excess money to an address of the same kind as the largest output
 #[wasm_bindgen]
-pub struct OutputPolicy(txbuilder::OutputPolicy);
+pub struct OutputPolicy(OutputPolicyVariant);
+
+enum OutputPolicyVariant {
+    Chain(txbuilder::OutputPolicy),
+    MergeIntoExisting(chain_addr::Address),
+    ChangeLikeOutput(
+        crypto::PublicKey<crypto::Ed25519>,
+        crypto::PublicKey<crypto::Ed25519>,
+        chain_addr::Discrimination,
+    ),
+}
 
 impl From<txbuilder::OutputPolicy> for OutputPolicy {
     fn from(output_policy: txbuilder::OutputPolicy) -> OutputPolicy {
-        OutputPolicy(output_policy)
+        OutputPolicy(OutputPolicyVariant::Chain(output_policy))
     }
 }
 
@@ -597,6 +1645,34 @@ impl OutputPolicy {
     pub fn one(address: Address) -> OutputPolicy {
         txbuilder::OutputPolicy::One(address.0).into()
     }
+
+    /// add the excess money to the output already going to the given address, instead of
+    /// adding a new change output. If there is no output to that address this is the same as `one`
+    pub fn merge_into_existing(address: Address) -> OutputPolicy {
+        OutputPolicy(OutputPolicyVariant::MergeIntoExisting(address.0))
+    }
+
+    /// send the excess money to a new address of the given key, of the same kind as the
+    /// address of the largest output (the first one when several have the same value), so the
+    /// change output looks like the payment:
+    /// * single: a single address of the key
+    /// * group: a group address of the key, delegating to the wallet's own `delegation_key`
+    ///   (never to the account of the payee)
+    /// * account: an account address of the key
+    ///
+    /// When there are no outputs a single address is used. The change is never merged into
+    /// the existing outputs, as they may not belong to the wallet (see `merge_into_existing`)
+    pub fn change_like_output(
+        change_key: PublicKey,
+        delegation_key: PublicKey,
+        discrimination: AddressDiscrimination,
+    ) -> OutputPolicy {
+        OutputPolicy(OutputPolicyVariant::ChangeLikeOutput(
+            change_key.0,
+            delegation_key.0,
+            discrimination.into(),
+        ))
+    }
 }
 
 /// Builder pattern implementation for signing a Transaction (adding witnesses)
@@ -618,11 +1694,18 @@ impl OutputPolicy {
 /// const signedTx = finalizer.build();
 /// ```
 #[wasm_bindgen]
-pub struct TransactionFinalizer(txbuilder::TransactionFinalizer);
+pub struct TransactionFinalizer(
+    txbuilder::TransactionFinalizer,
+    Vec<Option<account::SpendingCounter>>,
+);
 
 impl From<txbuilder::TransactionFinalizer> for TransactionFinalizer {
     fn from(finalizer: txbuilder::TransactionFinalizer) -> TransactionFinalizer {
-        TransactionFinalizer(finalizer)
+        let inputs = match &finalizer {
+            txbuilder::TransactionFinalizer::Type1(tx, _) => tx.inputs.len(),
+            txbuilder::TransactionFinalizer::Type2(tx, _) => tx.inputs.len(),
+        };
+        TransactionFinalizer(finalizer, vec![None; inputs])
     }
 }
 
@@ -630,14 +1713,25 @@ impl From<txbuilder::TransactionFinalizer> for TransactionFinalizer {
 impl TransactionFinalizer {
     #[wasm_bindgen(constructor)]
     pub fn new(transaction: Transaction) -> Self {
-        TransactionFinalizer(match transaction.0 {
+        let finalizer = match transaction.0 {
             EitherTransaction::TransactionWithCertificate(tx) => {
                 txbuilder::TransactionFinalizer::new_cert(tx)
             }
             EitherTransaction::TransactionWithoutCertificate(tx) => {
                 txbuilder::TransactionFinalizer::new_trans(tx)
             }
-        })
+        };
+        TransactionFinalizer(finalizer, transaction.2)
+    }
+
+    /// Get the spending counter of the input at the given index, this is the counter to use
+    /// in its account witness (see `Witness.for_account`). Only known if the input was
+    /// built with `Input.from_account_with_counter`
+    pub fn spending_counter(&self, index: usize) -> Option<SpendingCounter> {
+        self.1
+            .get(index)
+            .and_then(|spending_counter| *spending_counter)
+            .map(SpendingCounter)
     }
 
     /// Set the witness for the corresponding index, the index corresponds to the order in which the inputs were added to the transaction
@@ -651,6 +1745,52 @@ impl TransactionFinalizer {
         self.0.get_txid().into()
     }
 
+    /// Check the witnesses set so far, returning the status of each index. The utxo witnesses are
+    /// checked against the public keys expected for each input (`signers.get(i)` is the key
+    /// expected to sign the input `i`, it is ignored for account inputs), the account witnesses
+    /// against the account of the input and its spending counter (see `spending_counter`).
+    /// The status is one of
+    /// * "valid": the witness signature matches the signer
+    /// * "invalid": the witness signature doesn't match the signer, there is no signer for that
+    /// index, or the kind of the witness doesn't match the kind of the input
+    /// * "missing": there is no witness for that index yet
+    /// * "unverified": the witness of an account input whose spending counter is not known
+    /// (the input was not built with `Input.from_account_with_counter`), so it can't be checked
+    pub fn verify_all(&self, genesis_hash: &Hash, signers: PublicKeys) -> Vec<JsValue> {
+        let (inputs, witnesses) = match &self.0 {
+            txbuilder::TransactionFinalizer::Type1(tx, witnesses) => (&tx.inputs, witnesses),
+            txbuilder::TransactionFinalizer::Type2(tx, witnesses) => (&tx.inputs, witnesses),
+        };
+        let txid = self.0.get_txid();
+        inputs
+            .iter()
+            .zip(witnesses.iter())
+            .enumerate()
+            .map(|(index, (input, witness))| {
+                let status = witness_status(
+                    input,
+                    witness.as_ref(),
+                    signers.0.get(index),
+                    self.1
+                        .get(index)
+                        .and_then(|spending_counter| spending_counter.as_ref()),
+                    &genesis_hash.0,
+                    &txid,
+                );
+                JsValue::from_str(status.as_str())
+            })
+            .collect()
+    }
+
+    /// Get a copy of the unsigned Transaction being finalized
+    pub fn transaction(&self) -> Transaction {
+        let transaction: Transaction = match &self.0 {
+            txbuilder::TransactionFinalizer::Type1(tx, _) => tx.clone().into(),
+            txbuilder::TransactionFinalizer::Type2(tx, _) => tx.clone().into(),
+        };
+        transaction.with_spending_counters(self.1.clone())
+    }
+
     pub fn build(self) -> Result<GeneratedTransaction, JsValue> {
         self.0
             .build()
@@ -659,6 +1799,193 @@ impl TransactionFinalizer {
     }
 }
 
+/// Status of the witness of an input, see `TransactionFinalizer.verify_all`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WitnessStatus {
+    Valid,
+    Invalid,
+    Missing,
+    Unverified,
+}
+
+impl WitnessStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            WitnessStatus::Valid => "valid",
+            WitnessStatus::Invalid => "invalid",
+            WitnessStatus::Missing => "missing",
+            WitnessStatus::Unverified => "unverified",
+        }
+    }
+}
+
+/// Check the witness of an input, see `TransactionFinalizer.verify_all`. Utxo witnesses are
+/// checked with the key expected to sign the input, account witnesses with the account of
+/// the input and its spending counter
+fn witness_status(
+    input: &tx::Input,
+    witness: Option<&tx::Witness>,
+    signer: Option<&PublicKey>,
+    spending_counter: Option<&account::SpendingCounter>,
+    genesis_hash: &key::Hash,
+    txid: &tx::TransactionSignDataHash,
+) -> WitnessStatus {
+    let verification = match (input.to_enum(), witness) {
+        (_, None) => return WitnessStatus::Missing,
+        (tx::InputEnum::AccountInput(account, _), Some(tx::Witness::Account(signature))) => {
+            let key: crypto::PublicKey<crypto::Ed25519> = match account.to_single_account() {
+                Some(key) => key.into(),
+                None => return WitnessStatus::Invalid,
+            };
+            let spending_counter = match spending_counter {
+                Some(spending_counter) => spending_counter,
+                None => return WitnessStatus::Unverified,
+            };
+            signature.verify(
+                &key,
+                &tx::WitnessAccountData::new(genesis_hash, txid, spending_counter),
+            )
+        }
+        (tx::InputEnum::AccountInput(_, _), Some(_))
+        | (tx::InputEnum::UtxoInput(_), Some(tx::Witness::Account(_))) => {
+            return WitnessStatus::Invalid
+        }
+        (tx::InputEnum::UtxoInput(_), Some(witness)) => match signer {
+            Some(signer) => witness.verify_utxo(&signer.0, genesis_hash, txid),
+            None => return WitnessStatus::Invalid,
+        },
+    };
+    match verification {
+        crypto::Verification::Success => WitnessStatus::Valid,
+        crypto::Verification::Failed => WitnessStatus::Invalid,
+    }
+}
+
+/// Check the witnesses of every input of the transaction (see `verify_transactions`), the
+/// witness of an utxo input without signer can't be checked so it is unverified
+fn witnesses_status<Extra>(
+    auth: &tx::AuthenticatedTransaction<chain_addr::Address, Extra>,
+    txid: &tx::TransactionSignDataHash,
+    genesis_hash: &key::Hash,
+    signers: Option<&(PublicKeys, SpendingCounters)>,
+) -> WitnessStatus {
+    if auth.witnesses.len() != auth.transaction.inputs.len() {
+        return WitnessStatus::Invalid;
+    }
+    let statuses: Vec<WitnessStatus> = auth
+        .transaction
+        .inputs
+        .iter()
+        .zip(auth.witnesses.iter())
+        .enumerate()
+        .map(|(index, (input, witness))| {
+            let signer = signers.and_then(|(signers, _)| signers.0.get(index));
+            let spending_counter = signers
+                .and_then(|(_, spending_counters)| spending_counters.0.get(index))
+                .map(|spending_counter| &spending_counter.0);
+            match (input.to_enum(), witness, signer) {
+                (tx::InputEnum::UtxoInput(_), tx::Witness::Account(_), _) => WitnessStatus::Invalid,
+                (tx::InputEnum::UtxoInput(_), _, None) => WitnessStatus::Unverified,
+                _ => witness_status(
+                    input,
+                    Some(witness),
+                    signer,
+                    spending_counter,
+                    genesis_hash,
+                    txid,
+                ),
+            }
+        })
+        .collect();
+    if statuses.contains(&WitnessStatus::Invalid) {
+        WitnessStatus::Invalid
+    } else if statuses.contains(&WitnessStatus::Unverified) {
+        WitnessStatus::Unverified
+    } else {
+        WitnessStatus::Valid
+    }
+}
+
+/// Keys and spending counters expected to sign the transactions given to
+/// `verify_transactions`, one entry for each fragment in the same order as the fragments
+/// (the entries of the fragments that are not transactions are ignored). In each entry
+/// `signers.get(i)` is the key expected to sign the utxo input `i` of the transaction and
+/// `spending_counters.get(i)` the spending counter of its account input `i`, as in
+/// `GeneratedTransaction.validate`
+#[wasm_bindgen]
+pub struct FragmentSigners(Vec<(PublicKeys, SpendingCounters)>);
+
+#[wasm_bindgen]
+impl FragmentSigners {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> FragmentSigners {
+        FragmentSigners(vec![])
+    }
+
+    pub fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn add(&mut self, signers: PublicKeys, spending_counters: SpendingCounters) {
+        self.0.push((signers, spending_counters));
+    }
+}
+
+/// Check the witnesses of every transaction in the given fragments, returning the status of
+/// each fragment. `signers.get(i)` holds the keys and spending counters of the fragment `i`,
+/// see `FragmentSigners`.
+///
+/// Besides the fragments and the genesis hash the signers are needed: an utxo input only
+/// points to the output it spends, not to the key owning it, and an account witness signs
+/// the spending counter of the account, which is not part of the transaction. The witnesses
+/// that can't be checked because their signer or spending counter is not given are reported
+/// as unverified.
+/// The status is one of
+/// * "valid": every witness is valid
+/// * "invalid": the number of witnesses is not the number of inputs, or some witness is
+/// invalid (it doesn't match its signer or spending counter, or the kind of its input)
+/// * "unverified": the witnesses that can be checked are valid, but the signer of some utxo
+/// input or the spending counter of some account input is missing
+/// * "not_transaction": the fragment doesn't contain a transaction
+///
+/// ```javascript
+/// const signers = new FragmentSigners();
+/// signers.add(firstTransactionSigners, firstTransactionSpendingCounters);
+/// const statuses = verify_transactions(block.fragments(), genesisHash, signers);
+/// ```
+#[wasm_bindgen]
+pub fn verify_transactions(
+    fragments: &Fragments,
+    genesis_hash: &Hash,
+    signers: &FragmentSigners,
+) -> Vec<JsValue> {
+    fragments
+        .0
+        .iter()
+        .enumerate()
+        .map(|(index, fragment)| {
+            let status = match &fragment.0 {
+                chain::fragment::Fragment::Transaction(auth) => witnesses_status(
+                    auth,
+                    &auth.transaction.hash(),
+                    &genesis_hash.0,
+                    signers.0.get(index),
+                )
+                .as_str(),
+                chain::fragment::Fragment::Certificate(auth) => witnesses_status(
+                    auth,
+                    &auth.transaction.hash(),
+                    &genesis_hash.0,
+                    signers.0.get(index),
+                )
+                .as_str(),
+                _ => "not_transaction",
+            };
+            JsValue::from_str(status)
+        })
+        .collect()
+}
+
 /// Type for representing a Transaction with Witnesses (signatures)
 #[wasm_bindgen]
 pub struct GeneratedTransaction(txbuilder::GeneratedTransaction);
@@ -686,6 +2013,253 @@ impl GeneratedTransaction {
             chain::txbuilder::GeneratedTransaction::Type2(auth) => auth.transaction.clone().into(),
         }
     }
+
+    /// Get a copy of the signed transaction, useful for keeping it after
+    /// consuming it with `Fragment.from_generated_transaction`
+    pub fn clone_tx(&self) -> GeneratedTransaction {
+        match &self.0 {
+            chain::txbuilder::GeneratedTransaction::Type1(auth) => {
+                txbuilder::GeneratedTransaction::Type1(auth.clone())
+            }
+            chain::txbuilder::GeneratedTransaction::Type2(auth) => {
+                txbuilder::GeneratedTransaction::Type2(auth.clone())
+            }
+        }
+        .into()
+    }
+
+    /// Serialize the signed transaction, the encoding is the same used for the Fragment
+    /// obtained with `Fragment.from_generated_transaction`
+    pub fn as_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        self.to_fragment()
+            .serialize_as_vec()
+            .map_err(|error| JsValue::from_str(&format!("{}", error)))
+    }
+
+    /// Get the bech32 representation (with the "message" prefix) of the Fragment
+    /// containing this transaction
+    pub fn to_bech32(&self) -> Result<String, JsValue> {
+        bech32::encode("message", self.as_bytes()?.to_base32())
+            .map_err(|error| JsValue::from_str(&format!("{}", error)))
+    }
+
+    /// Get how the serialized size (see `as_bytes`) is split between the inputs, the outputs
+    /// and the witnesses, the rest (headers, lengths and the certificate) is the overhead
+    /// ```javascript
+    /// signedTx.size_breakdown();
+    /// // { inputs, outputs, witnesses, overhead } in bytes
+    /// ```
+    pub fn size_breakdown(&self) -> Result<JsValue, JsValue> {
+        let total = self.as_bytes()?.len();
+        let (inputs, outputs, witnesses) = match &self.0 {
+            chain::txbuilder::GeneratedTransaction::Type1(auth) => components_size(auth)?,
+            chain::txbuilder::GeneratedTransaction::Type2(auth) => components_size(auth)?,
+        };
+        let breakdown = SizeBreakdown {
+            inputs,
+            outputs,
+            witnesses,
+            overhead: total - inputs - outputs - witnesses,
+        };
+        JsValue::from_serde(&breakdown).map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
+
+    /// Get the fee paid (see `Transaction.implied_fee`), the size in bytes (see `as_bytes`)
+    /// and the fee per byte of the transaction, for ordering transactions by fee, along with
+    /// the minimum fee computed with the given algorithm. The fees are strings, as with `Value`.
+    /// The metrics are given for transactions paying less than the minimum fee too, so they
+    /// can be ranked or evicted, see `validate` for checking the fee
+    /// ```javascript
+    /// signedTx.mempool_metrics(feeAlgorithm);
+    /// // { fee, size, feePerByte, minimumFee }
+    /// ```
+    pub fn mempool_metrics(&self, fee: &Fee) -> Result<JsValue, JsValue> {
+        let transaction = self.transaction();
+        let paid = transaction.implied_fee()?;
+        let minimum = fee
+            .calculate(transaction)
+            .ok_or_else(|| JsValue::from_str("Fee out of range"))?;
+        let size = self.as_bytes()?.len();
+        let metrics = MempoolMetrics {
+            fee: paid.to_str(),
+            size,
+            fee_per_byte: *paid.as_ref() as f64 / size as f64,
+            minimum_fee: minimum.to_str(),
+        };
+        JsValue::from_serde(&metrics).map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
+
+    /// Check that the transaction can be sent: the inputs pay the outputs and at least the fee
+    /// computed with the given algorithm, and every input has a valid witness.
+    /// `signers.get(i)` is the key expected to sign the utxo input `i` and
+    /// `spending_counters.get(i)` the spending counter of the account input `i`
+    /// (see `TransactionFinalizer.verify_all`), the entries of the other kind of input are
+    /// ignored. Fails with the description of the first problem found.
+    ///
+    /// The spending counters are needed because an account witness signs the spending
+    /// counter of the account along with the transaction, and neither the signed transaction
+    /// nor the input record it: without them an account witness can't be checked, so an
+    /// account input without spending counter is an error
+    pub fn validate(
+        &self,
+        fee: &Fee,
+        genesis_hash: &Hash,
+        signers: PublicKeys,
+        spending_counters: SpendingCounters,
+    ) -> Result<(), JsValue> {
+        self.paid_fee(fee)?;
+        let (inputs, witnesses) = match &self.0 {
+            chain::txbuilder::GeneratedTransaction::Type1(auth) => {
+                (&auth.transaction.inputs, &auth.witnesses)
+            }
+            chain::txbuilder::GeneratedTransaction::Type2(auth) => {
+                (&auth.transaction.inputs, &auth.witnesses)
+            }
+        };
+        if inputs.len() != witnesses.len() {
+            return Err(JsValue::from_str(&format!(
+                "Invalid number of witnesses. Found {}, expected: {}",
+                witnesses.len(),
+                inputs.len()
+            )));
+        }
+        let txid = self.id().0;
+        for (index, (input, witness)) in inputs.iter().zip(witnesses.iter()).enumerate() {
+            match (input.get_type(), witness) {
+                (tx::InputType::Account, tx::Witness::Account(_)) => (),
+                (tx::InputType::Account, _) | (_, tx::Witness::Account(_)) => {
+                    return Err(JsValue::from_str(&format!(
+                        "The witness {} doesn't match the kind of its input",
+                        index
+                    )))
+                }
+                (tx::InputType::Utxo, _) => (),
+            }
+            match witness_status(
+                input,
+                Some(witness),
+                signers.0.get(index),
+                spending_counters
+                    .0
+                    .get(index)
+                    .map(|spending_counter| &spending_counter.0),
+                &genesis_hash.0,
+                &txid,
+            ) {
+                WitnessStatus::Valid => (),
+                WitnessStatus::Unverified => {
+                    return Err(JsValue::from_str(&format!(
+                        "The witness {} can't be verified, the spending counter of its input is missing",
+                        index
+                    )))
+                }
+                WitnessStatus::Invalid => {
+                    return Err(JsValue::from_str(&format!(
+                        "The witness {} is invalid",
+                        index
+                    )))
+                }
+                WitnessStatus::Missing => {
+                    return Err(JsValue::from_str(&format!(
+                        "The witness {} is missing",
+                        index
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Deserialize a signed transaction from the bytes produced by `as_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<GeneratedTransaction, JsValue> {
+        match chain::fragment::Fragment::deserialize(bytes)
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))?
+        {
+            chain::fragment::Fragment::Transaction(auth) => {
+                Ok(txbuilder::GeneratedTransaction::Type1(auth).into())
+            }
+            chain::fragment::Fragment::Certificate(auth) => {
+                Ok(txbuilder::GeneratedTransaction::Type2(auth).into())
+            }
+            _ => Err(JsValue::from_str("Invalid message type")),
+        }
+    }
+}
+
+impl Transaction {
+    fn with_spending_counters(
+        mut self,
+        spending_counters: Vec<Option<account::SpendingCounter>>,
+    ) -> Self {
+        self.2 = spending_counters;
+        self
+    }
+}
+
+impl GeneratedTransaction {
+    /// Get the fee paid by the transaction, failing if it is below the
+    /// fee computed with the given algorithm
+    fn paid_fee(&self, fee: &Fee) -> Result<Value, JsValue> {
+        let transaction = self.transaction();
+        let paid = transaction.implied_fee()?;
+        let minimum = fee
+            .calculate(transaction)
+            .ok_or_else(|| JsValue::from_str("Fee out of range"))?;
+        if paid.0 < minimum.0 {
+            return Err(JsValue::from_str(&format!(
+                "Fee {} is below the minimum fee {}",
+                paid.0, minimum.0
+            )));
+        }
+        Ok(paid)
+    }
+
+    fn to_fragment(&self) -> chain::fragment::Fragment {
+        match &self.0 {
+            chain::txbuilder::GeneratedTransaction::Type1(auth) => {
+                chain::fragment::Fragment::Transaction(auth.clone())
+            }
+            chain::txbuilder::GeneratedTransaction::Type2(auth) => {
+                chain::fragment::Fragment::Certificate(auth.clone())
+            }
+        }
+    }
+}
+
+/// Serialized size of the inputs, outputs and witnesses of the transaction
+fn components_size<Extra>(
+    auth: &tx::AuthenticatedTransaction<chain_addr::Address, Extra>,
+) -> Result<(usize, usize, usize), JsValue> {
+    let mut inputs = 0;
+    for input in auth.transaction.inputs.iter() {
+        inputs += Input::from(input.clone()).as_bytes()?.len();
+    }
+    let mut outputs = 0;
+    for output in auth.transaction.outputs.iter() {
+        outputs += Output(output.clone()).as_bytes()?.len();
+    }
+    let mut witnesses = 0;
+    for witness in auth.witnesses.iter() {
+        witnesses += Witness(witness.clone()).as_bytes()?.len();
+    }
+    Ok((inputs, outputs, witnesses))
+}
+
+#[derive(serde::Serialize)]
+struct SizeBreakdown {
+    inputs: usize,
+    outputs: usize,
+    witnesses: usize,
+    overhead: usize,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MempoolMetrics {
+    fee: String,
+    size: usize,
+    fee_per_byte: f64,
+    minimum_fee: String,
 }
 
 /// Type for representing the hash of a Transaction, necessary for signing it
@@ -729,6 +2303,7 @@ impl From<key::Hash> for Hash {
 
 #[wasm_bindgen]
 impl Hash {
+    /// Compute the hash of the given bytes, use `from_hex` for getting an already computed hash
     pub fn from_bytes(bytes: &[u8]) -> Hash {
         key::Hash::hash_bytes(bytes).into()
     }
@@ -744,13 +2319,15 @@ impl Hash {
     }
 }
 
+/// The spending counter is not part of the input in the blockchain, it is only kept here
+/// (for account inputs) to have it at hand when signing
 #[wasm_bindgen]
 #[derive(Clone)]
-pub struct Input(tx::Input);
+pub struct Input(tx::Input, Option<account::SpendingCounter>);
 
 impl From<tx::Input> for Input {
     fn from(input: tx::Input) -> Input {
-        Input(input)
+        Input(input, None)
     }
 }
 
@@ -761,11 +2338,47 @@ impl From<tx::Input> for Input {
 #[wasm_bindgen]
 impl Input {
     pub fn from_utxo(utxo_pointer: &UtxoPointer) -> Self {
-        Input(tx::Input::from_utxo(utxo_pointer.0))
+        tx::Input::from_utxo(utxo_pointer.0).into()
+    }
+
+    /// Same as `from_utxo`, building the UtxoPointer from its parts
+    pub fn from_utxo_parts(fragment_id: FragmentId, output_index: u8, value: Value) -> Self {
+        Input::from_utxo(&UtxoPointer::new(fragment_id, output_index, value))
+    }
+
+    /// Get the input spending the given output, which is the output number `index`
+    /// of the transaction `fragment_id`
+    pub fn from_output(output: &Output, fragment_id: &FragmentId, index: u8) -> Self {
+        tx::Input::from_utxo(tx::UtxoPointer {
+            transaction_id: fragment_id.0,
+            output_index: index,
+            value: output.0.value,
+        })
+        .into()
     }
 
     pub fn from_account(account: &Account, v: Value) -> Self {
-        Input(tx::Input::from_account(account.0.clone(), v.0))
+        tx::Input::from_account(account.0.clone(), v.0).into()
+    }
+
+    /// Same as `from_account`, but keeping the spending counter to be used in the witness
+    /// of this input, which can be read back with `spending_counter`
+    pub fn from_account_with_counter(
+        account: &Account,
+        v: Value,
+        spending_counter: SpendingCounter,
+    ) -> Self {
+        Input(
+            tx::Input::from_account(account.0.clone(), v.0),
+            Some(spending_counter.0),
+        )
+    }
+
+    /// Get the spending counter given in `from_account_with_counter`, if any.
+    /// It is kept by `TransactionBuilder.add_input` and in the transactions finalized by the
+    /// builder, but inputs read from a deserialized transaction don't have it
+    pub fn spending_counter(&self) -> Option<SpendingCounter> {
+        self.1.map(SpendingCounter)
     }
 
     /// Get the kind of Input, this can be either "Account" or "Utxo"
@@ -788,13 +2401,38 @@ impl Input {
         }
     }
 
-    /// Get the source Account if the Input type is Account
+    /// Get the source Account if the Input type is Account. The input doesn't tell
+    /// if the account is a single or a multisig account, so it is a single account when
+    /// the identifier is a public key (see `Account.kind`)
     pub fn get_account(&self) -> Result<Account, JsValue> {
         match self.0.to_enum() {
             tx::InputEnum::AccountInput(account, _) => Ok(account.into()),
             _ => Err(JsValue::from_str("Input is not from account")),
         }
     }
+
+    /// Check if both inputs refer to the same source of value (same utxo pointer or
+    /// same account) with the same value
+    pub fn equals(&self, other: &Input) -> bool {
+        self.0 == other.0
+    }
+
+    /// Serialize the input as it is encoded in a transaction.
+    /// The spending counter is not part of the encoding
+    pub fn as_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        self.0
+            .serialize_as_vec()
+            .map_err(|error| JsValue::from_str(&format!("{}", error)))
+    }
+
+    /// Deserialize an input from the bytes produced by `as_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Input, JsValue> {
+        let mut buf = ReadBuf::from(bytes);
+        let input = tx::Input::read(&mut buf).map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+        buf.expect_end()
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+        Ok(input.into())
+    }
 }
 
 /// Unspent transaction pointer. This is composed of:
@@ -821,16 +2459,43 @@ impl UtxoPointer {
             value: value.0,
         })
     }
+
+    pub fn fragment_id(&self) -> FragmentId {
+        self.0.transaction_id.into()
+    }
+
+    pub fn output_index(&self) -> u8 {
+        self.0.output_index
+    }
+
+    pub fn value(&self) -> Value {
+        self.0.value.into()
+    }
 }
 
 /// This is either an single account or a multisig account depending on the witness type
+///
+/// Both kinds of identifiers are 32 bytes, so the kind is kept next to the identifier.
+/// An account read from a transaction input is a single account if its identifier is a
+/// public key, its kind is unknown otherwise (a multisig account or a malformed identifier)
 #[wasm_bindgen]
 #[derive(Debug)]
-pub struct Account(tx::AccountIdentifier);
+pub struct Account(tx::AccountIdentifier, AccountKind);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AccountKind {
+    Single,
+    Multisig,
+    Unknown,
+}
 
 impl From<tx::AccountIdentifier> for Account {
     fn from(account_identifier: tx::AccountIdentifier) -> Account {
-        Account(account_identifier)
+        let kind = match account_identifier.to_single_account() {
+            Some(_) => AccountKind::Single,
+            None => AccountKind::Unknown,
+        };
+        Account(account_identifier, kind)
     }
 }
 
@@ -838,25 +2503,70 @@ impl From<tx::AccountIdentifier> for Account {
 impl Account {
     pub fn from_address(address: &Address) -> Result<Account, JsValue> {
         if let chain_addr::Kind::Account(key) = address.0.kind() {
-            Ok(Account(tx::AccountIdentifier::from_single_account(
-                key.clone().into(),
-            )))
+            Ok(Account::from_public_key(PublicKey(key.clone())))
         } else {
             Err(JsValue::from_str("Address is not account"))
         }
     }
 
-    pub fn to_address(&self) -> Address {
-        let kind = match self.0.to_single_account() {
-            Some(key) => chain_addr::Kind::Account(key.into()),
-            None => panic!(),
+    /// Get the address of a single account, multisig accounts don't have an address.
+    /// Fails too if the identifier is not a public key (see `kind`)
+    pub fn to_address(&self) -> Result<Address, JsValue> {
+        let key = match self.1 {
+            AccountKind::Single => self
+                .0
+                .to_single_account()
+                .ok_or_else(|| JsValue::from_str("Malformed single account"))?,
+            AccountKind::Multisig => {
+                return Err(JsValue::from_str(
+                    "Multisig account doesn't have an address",
+                ))
+            }
+            AccountKind::Unknown => {
+                return Err(JsValue::from_str("Account is not a single account"))
+            }
         };
         let discriminant = chain_addr::Discrimination::Production;
-        chain_addr::Address(discriminant, kind).into()
+        Ok(chain_addr::Address(discriminant, chain_addr::Kind::Account(key.into())).into())
     }
 
     pub fn from_public_key(key: PublicKey) -> Account {
-        Account(tx::AccountIdentifier::from_single_account(key.0.into()))
+        Account(
+            tx::AccountIdentifier::from_single_account(key.0.into()),
+            AccountKind::Single,
+        )
+    }
+
+    /// Get a multisig account from its 32 bytes identifier
+    pub fn from_multisig_identifier(bytes: &[u8]) -> Result<Account, JsValue> {
+        let identifier = <[u8; 32]>::try_from(bytes).map_err(|_| {
+            JsValue::from_str(&format!(
+                "Invalid multisig identifier length. Found {}, expected: 32",
+                bytes.len()
+            ))
+        })?;
+        Ok(Account(
+            tx::AccountIdentifier::from_multi_account(chain::multisig::Identifier::from(
+                identifier,
+            )),
+            AccountKind::Multisig,
+        ))
+    }
+
+    /// Get the kind of account, this can be "Single", "Multisig" or "Unknown" (for the
+    /// accounts read from a transaction input whose identifier is not a public key, see
+    /// `Input.get_account`)
+    pub fn kind(&self) -> String {
+        match self.1 {
+            AccountKind::Single => "Single".to_string(),
+            AccountKind::Multisig => "Multisig".to_string(),
+            AccountKind::Unknown => "Unknown".to_string(),
+        }
+    }
+
+    /// Check if the account is known to be a multisig account, false if the kind is unknown
+    pub fn is_multisig(&self) -> bool {
+        self.1 == AccountKind::Multisig
     }
 }
 
@@ -873,13 +2583,53 @@ impl From<tx::Output<chain_addr::Address>> for Output {
 
 #[wasm_bindgen]
 impl Output {
+    pub fn new(address: Address, value: Value) -> Output {
+        tx::Output {
+            address: address.0,
+            value: value.0,
+        }
+        .into()
+    }
+
     pub fn address(&self) -> Address {
         self.0.address.clone().into()
     }
 
+    /// Check if the output goes to an account address
+    pub fn is_account_address(&self) -> bool {
+        match self.0.address.kind() {
+            chain_addr::Kind::Account(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn value(&self) -> Value {
         self.0.value.into()
     }
+
+    /// Serialize the output as it is encoded in a transaction: the address followed by the value
+    pub fn as_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        let mut bytes = self
+            .0
+            .address
+            .serialize_as_vec()
+            .map_err(|error| JsValue::from_str(&format!("{}", error)))?;
+        self.0
+            .value
+            .serialize(&mut bytes)
+            .map_err(|error| JsValue::from_str(&format!("{}", error)))?;
+        Ok(bytes)
+    }
+
+    /// Deserialize an output from the bytes produced by `as_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Output, JsValue> {
+        let mut buf = ReadBuf::from(bytes);
+        let output = tx::Output::<chain_addr::Address>::read(&mut buf)
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+        buf.expect_end()
+            .map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+        Ok(output.into())
+    }
 }
 
 /// Type used for representing certain amount of lovelaces.
@@ -917,6 +2667,40 @@ impl Value {
         format!("{}", self.0)
     }
 
+    /// Read the value from its 8 bytes big endian representation
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Value, JsValue> {
+        Value::u64_bytes(bytes).map(|bytes| u64::from_be_bytes(bytes).into())
+    }
+
+    /// Read the value from its 8 bytes little endian representation
+    pub fn from_le_bytes(bytes: &[u8]) -> Result<Value, JsValue> {
+        Value::u64_bytes(bytes).map(|bytes| u64::from_le_bytes(bytes).into())
+    }
+
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        self.0.as_ref().to_be_bytes().to_vec()
+    }
+
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        self.0.as_ref().to_le_bytes().to_vec()
+    }
+
+    /// Check if the value can be represented exactly by a javascript Number,
+    /// that is if it is not greater than `Number.MAX_SAFE_INTEGER` (2^53 - 1)
+    pub fn fits_in_f64_safe(&self) -> bool {
+        *self.0.as_ref() <= (1 << 53) - 1
+    }
+
+    /// Compare with the other value, returning -1 if this value is lower, 0 if they are equal
+    /// and 1 if this value is greater
+    pub fn compare(&self, other: &Value) -> i32 {
+        match self.0.as_ref().cmp(other.0.as_ref()) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+    }
+
     pub fn checked_add(&self, other: &Value) -> Result<Value, JsValue> {
         self.0
             .add(other.0)
@@ -930,6 +2714,23 @@ impl Value {
             .map_err(|e| JsValue::from_str(&format!("{}", &format!("{}", e))))
             .map(Value)
     }
+
+    pub fn checked_mul(&self, other: &Value) -> Result<Value, JsValue> {
+        self.0
+            .as_ref()
+            .checked_mul(*other.0.as_ref())
+            .map(Value::from)
+            .ok_or_else(|| JsValue::from_str("Value overflow"))
+    }
+
+    /// Integer division, the result is rounded down
+    pub fn checked_div(&self, other: &Value) -> Result<Value, JsValue> {
+        self.0
+            .as_ref()
+            .checked_div(*other.0.as_ref())
+            .map(Value::from)
+            .ok_or_else(|| JsValue::from_str("Division by zero"))
+    }
 }
 
 impl From<value::Value> for Value {
@@ -938,6 +2739,21 @@ impl From<value::Value> for Value {
     }
 }
 
+impl Value {
+    fn u64_bytes(bytes: &[u8]) -> Result<[u8; 8], JsValue> {
+        if bytes.len() == std::mem::size_of::<u64>() {
+            let mut array = [0u8; 8];
+            array.copy_from_slice(bytes);
+            Ok(array)
+        } else {
+            Err(JsValue::from_str(&format!(
+                "Invalid array length. Found {}, expected: 8",
+                bytes.len()
+            )))
+        }
+    }
+}
+
 #[wasm_bindgen]
 pub struct U128(u128);
 
@@ -992,6 +2808,9 @@ pub struct Certificate(certificate::Certificate);
 #[wasm_bindgen]
 impl Certificate {
     /// Create a stake delegation certificate from account (stake key) to pool_id
+    ///
+    /// The delegation is always to a single pool, splitting the stake of one account
+    /// between several pools (ratio delegation) is not supported by the chain
     pub fn stake_delegation(pool_id: StakePoolId, account: PublicKey) -> Certificate {
         let content = certificate::StakeDelegation {
             stake_key_id: tx::AccountIdentifier::from_single_account(account.0.into()),
@@ -1012,6 +2831,88 @@ impl Certificate {
         .into()
     }
 
+    /// Create a certificate retiring the given pool, the registration information of the pool
+    /// is included so its owners can sign the retirement.
+    /// The chain retirement has no retirement time, the pool is retired when the
+    /// certificate is applied
+    pub fn stake_pool_retirement(pool_id: StakePoolId, pool_info: StakePoolInfo) -> Certificate {
+        let content = certificate::StakePoolRetirement {
+            pool_id: pool_id.0,
+            pool_info: pool_info.0,
+        };
+        certificate::Certificate {
+            content: certificate::CertificateContent::StakePoolRetirement(content),
+            signatures: vec![],
+        }
+        .into()
+    }
+
+    /// Get the kind of certificate, this can be "StakeDelegation", "StakePoolRegistration"
+    /// or "StakePoolRetirement"
+    pub fn get_type(&self) -> String {
+        match self.0.content {
+            certificate::CertificateContent::StakeDelegation(_) => "StakeDelegation",
+            certificate::CertificateContent::StakePoolRegistration(_) => "StakePoolRegistration",
+            certificate::CertificateContent::StakePoolRetirement(_) => "StakePoolRetirement",
+        }
+        .to_string()
+    }
+
+    /// Get the serial of the pool registration, fails for the other certificate types
+    pub fn pool_serial(&self) -> Result<U128, JsValue> {
+        match &self.0.content {
+            certificate::CertificateContent::StakePoolRegistration(info) => Ok(info.serial.into()),
+            _ => Err(JsValue::from_str(
+                "Certificate is not a stake pool registration",
+            )),
+        }
+    }
+
+    /// Check if both certificates have the same content and signatures
+    pub fn equals(&self, other: &Certificate) -> bool {
+        self.0 == other.0
+    }
+
+    /// Check if both certificates have the same content, ignoring the signatures
+    pub fn content_equals(&self, other: &Certificate) -> bool {
+        self.0.content == other.0.content
+    }
+
+    /// Check the signatures of the certificate against the keys in its content: the stake key
+    /// for a delegation (one signature), the owners for a pool registration or retirement (one
+    /// signature per owner, in the same order). Returns false if a signature is invalid or missing
+    pub fn verify_signatures(&self) -> Result<bool, JsValue> {
+        let (signers, message): (Vec<crypto::PublicKey<crypto::Ed25519>>, Vec<u8>) =
+            match &self.0.content {
+                certificate::CertificateContent::StakeDelegation(s) => {
+                    let stake_key = s.stake_key_id.to_single_account().ok_or_else(|| {
+                        JsValue::from_str("The stake key is not a single account")
+                    })?;
+                    (vec![stake_key.into()], certificate_content_bytes(s)?)
+                }
+                certificate::CertificateContent::StakePoolRegistration(s) => {
+                    (owner_keys(&s.owners), certificate_content_bytes(s)?)
+                }
+                certificate::CertificateContent::StakePoolRetirement(s) => (
+                    owner_keys(&s.pool_info.owners),
+                    certificate_content_bytes(s)?,
+                ),
+            };
+        if signers.len() != self.0.signatures.len() {
+            return Ok(false);
+        }
+        Ok(signers
+            .iter()
+            .zip(self.0.signatures.iter())
+            .all(|(signer, signature)| {
+                crypto::Signature::<[u8], crypto::Ed25519>::from_binary(signature.as_ref())
+                    .map(|signature| {
+                        signature.verify_slice(signer, &message) == crypto::Verification::Success
+                    })
+                    .unwrap_or(false)
+            }))
+    }
+
     /// Add signature to certificate
     pub fn sign(&mut self, private_key: PrivateKey) {
         let signature = match &self.0.content {
@@ -1035,10 +2936,19 @@ impl Certificate {
     }
 
     pub fn to_bech32(&self) -> Result<String, JsValue> {
-        Bech32::new("cert".to_string(), self.as_bytes()?.to_base32())
-            .map(|bech32| bech32.to_string())
+        bech32::encode("cert", self.as_bytes()?.to_base32())
             .map_err(|error| JsValue::from_str(&format!("{}", error)))
     }
+
+    /// Deserialize a certificate from the bytes produced by `as_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Certificate, JsValue> {
+        certificate_from_bytes(bytes).map(Certificate)
+    }
+
+    /// Get the certificate from its bech32 representation (with the "cert" prefix)
+    pub fn from_bech32(bech32_str: &str) -> Result<Certificate, JsValue> {
+        certificate_from_bech32(bech32_str).map(Certificate)
+    }
 }
 
 impl From<certificate::Certificate> for Certificate {
@@ -1047,6 +2957,47 @@ impl From<certificate::Certificate> for Certificate {
     }
 }
 
+fn owner_keys(owners: &[account::Identifier]) -> Vec<crypto::PublicKey<crypto::Ed25519>> {
+    owners.iter().map(|owner| owner.clone().into()).collect()
+}
+
+/// Get the bytes signed by the certificate signatures
+fn certificate_content_bytes<T: Serialize>(content: &T) -> Result<Vec<u8>, JsValue>
+where
+    T::Error: std::fmt::Display,
+{
+    content
+        .serialize_as_vec()
+        .map_err(|e| JsValue::from_str(&format!("{}", e)))
+}
+
+fn certificate_from_bech32(bech32_str: &str) -> Result<certificate::Certificate, JsValue> {
+    let (hrp, data) =
+        bech32::decode(bech32_str).map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+    if hrp != "cert" {
+        return Err(JsValue::from_str(&format!(
+            "Invalid prefix. Found {}, expected: cert",
+            hrp
+        )));
+    }
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+    certificate_from_bytes(&bytes)
+}
+
+fn certificate_from_bytes(bytes: &[u8]) -> Result<certificate::Certificate, JsValue> {
+    let mut buf = ReadBuf::from(bytes);
+    let certificate = certificate::Certificate::read(&mut buf)
+        .map_err(|e| JsValue::from_str(&format!("Malformed certificate: {}", e)))?;
+    buf.expect_end()
+        .map_err(|e| JsValue::from_str(&format!("Malformed certificate: {}", e)))?;
+    Ok(certificate)
+}
+
+/// Registration information of a stake pool: serial, owners and the initial leader keys.
+///
+/// The registration doesn't carry a dedicated reward account, the pool rewards are
+/// attributed to the owners, whose keys can be read with `owners` (and turned into
+/// account addresses with `Address.account_from_public_key`)
 #[wasm_bindgen]
 pub struct StakePoolInfo(chain::stake::StakePoolInfo);
 
@@ -1080,8 +3031,38 @@ impl StakePoolInfo {
         .into()
     }
 
-    pub fn id(&self) -> StakePoolId {
-        self.0.to_id().into()
+    /// Get the pool id, which is the hash of the serialized registration information
+    pub fn id(&self) -> StakePoolId {
+        self.0.to_id().into()
+    }
+
+    /// Get the public keys of the pool owners, in the registration order
+    pub fn owners(&self) -> PublicKeys {
+        PublicKeys(
+            self.0
+                .owners
+                .iter()
+                .map(|owner| PublicKey(owner.clone().into()))
+                .collect(),
+        )
+    }
+
+    pub fn kes_public_key(&self) -> KesPublicKey {
+        self.0.initial_key.kes_public_key.clone().into()
+    }
+
+    pub fn vrf_public_key(&self) -> VrfPublicKey {
+        self.0.initial_key.vrf_public_key.clone().into()
+    }
+
+    /// Get the bech32 representation of the pool KES public key
+    pub fn kes_bech32(&self) -> String {
+        self.0.initial_key.kes_public_key.to_bech32_str()
+    }
+
+    /// Get the bech32 representation of the pool VRF public key
+    pub fn vrf_bech32(&self) -> String {
+        self.0.initial_key.vrf_public_key.to_bech32_str()
     }
 }
 
@@ -1123,6 +3104,10 @@ impl KesPublicKey {
             .map(KesPublicKey)
             .map_err(|_| JsValue::from_str("Malformed kes public key"))
     }
+
+    pub fn to_bech32(&self) -> String {
+        self.0.to_bech32_str()
+    }
 }
 
 #[wasm_bindgen]
@@ -1141,6 +3126,10 @@ impl VrfPublicKey {
             .map(VrfPublicKey)
             .map_err(|_| JsValue::from_str("Malformed vrf public key"))
     }
+
+    pub fn to_bech32(&self) -> String {
+        self.0.to_bech32_str()
+    }
 }
 
 /// Amount of the balance in the transaction.
@@ -1172,6 +3161,31 @@ impl Balance {
             tx::Balance::Zero => Value(value::Value(0)),
         }
     }
+
+    /// Get the signed difference `self - other`, fails if the result
+    /// doesn't fit in a Value
+    pub fn difference(&self, other: &Balance) -> Result<Balance, JsValue> {
+        let difference = self.as_i128() - other.as_i128();
+        let value = u64::try_from(difference.abs())
+            .map(value::Value)
+            .map_err(|_| JsValue::from_str("Balance difference out of range"))?;
+        Ok(match difference {
+            d if d > 0 => tx::Balance::Positive(value),
+            d if d < 0 => tx::Balance::Negative(value),
+            _ => tx::Balance::Zero,
+        }
+        .into())
+    }
+}
+
+impl Balance {
+    fn as_i128(&self) -> i128 {
+        match self.0 {
+            tx::Balance::Positive(v) => i128::from(v.0),
+            tx::Balance::Negative(v) => -i128::from(v.0),
+            tx::Balance::Zero => 0,
+        }
+    }
 }
 
 /// Algorithm used to compute transaction fees
@@ -1185,11 +3199,25 @@ use fee::FeeAlgorithm;
 impl Fee {
     /// Linear algorithm, this is formed by: `coefficient * (#inputs + #outputs) + constant + certificate * #certificate
     pub fn linear_fee(constant: Value, coefficient: Value, certificate: Value) -> Fee {
-        Fee(FeeVariant::Linear(fee::LinearFee::new(
-            *constant.0.as_ref(),
-            *coefficient.0.as_ref(),
-            *certificate.0.as_ref(),
-        )))
+        Fee::linear_fee_rounded(constant, coefficient, certificate, Value::from(1))
+    }
+
+    /// Linear algorithm as in `linear_fee`, with the result rounded up to a multiple of `round_to`.
+    /// A `round_to` of zero or one doesn't round
+    pub fn linear_fee_rounded(
+        constant: Value,
+        coefficient: Value,
+        certificate: Value,
+        round_to: Value,
+    ) -> Fee {
+        Fee(FeeVariant::Linear(RoundedLinearFee {
+            linear: fee::LinearFee::new(
+                *constant.0.as_ref(),
+                *coefficient.0.as_ref(),
+                *certificate.0.as_ref(),
+            ),
+            round_to: *round_to.0.as_ref(),
+        }))
     }
 
     /// Compute the fee if possible (it can fail in case the values are out of range)
@@ -1206,10 +3234,97 @@ impl Fee {
         }
         .map(Value)
     }
+
+    /// Compute the fee of a transaction with the given number of inputs, outputs and
+    /// certificates, without building it. Fails if the result is out of range
+    pub fn estimate(
+        &self,
+        inputs: usize,
+        outputs: usize,
+        certificates: usize,
+    ) -> Result<Value, JsValue> {
+        match &self.0 {
+            FeeVariant::Linear(algorithm) => (inputs as u64)
+                .checked_add(outputs as u64)
+                .and_then(|io| io.checked_mul(algorithm.linear.coefficient))
+                .and_then(|fee| fee.checked_add(algorithm.linear.constant))
+                .and_then(|fee| {
+                    (certificates as u64)
+                        .checked_mul(algorithm.linear.certificate)
+                        .and_then(|certificate| fee.checked_add(certificate))
+                })
+                .and_then(|fee| algorithm.round(fee)),
+        }
+        .map(Value::from)
+        .ok_or_else(|| JsValue::from_str("Fee out of range"))
+    }
+
+    /// Get the extra fee paid for adding one input to a transaction. An utxo with a value
+    /// below this costs more to spend than what it adds to the transaction.
+    /// With a rounded fee this is the coefficient rounded up, the most that one input can add
+    pub fn dust_threshold(&self) -> Value {
+        match &self.0 {
+            FeeVariant::Linear(algorithm) => Value::from(
+                algorithm
+                    .round(algorithm.linear.coefficient)
+                    .unwrap_or(u64::max_value()),
+            ),
+        }
+    }
 }
 
 pub enum FeeVariant {
-    Linear(fee::LinearFee),
+    Linear(RoundedLinearFee),
+}
+
+/// Linear fee algorithm of the chain, with the result rounded up to a multiple of `round_to`
+#[derive(Clone, Copy)]
+pub struct RoundedLinearFee {
+    linear: fee::LinearFee,
+    round_to: u64,
+}
+
+impl RoundedLinearFee {
+    fn round(&self, fee: u64) -> Option<u64> {
+        if self.round_to <= 1 {
+            return Some(fee);
+        }
+        match fee % self.round_to {
+            0 => Some(fee),
+            remainder => fee.checked_add(self.round_to - remainder),
+        }
+    }
+}
+
+impl<P> FeeAlgorithm<P> for RoundedLinearFee
+where
+    fee::LinearFee: FeeAlgorithm<P>,
+{
+    fn calculate(&self, part: &P) -> Option<value::Value> {
+        self.linear
+            .calculate(part)
+            .and_then(|fee| self.round(fee.0))
+            .map(value::Value)
+    }
+}
+
+/// Fee algorithm that always charges the given fee, as long as it is not below
+/// the one computed by the `minimum` algorithm
+struct ExactFee {
+    minimum: RoundedLinearFee,
+    fee: value::Value,
+}
+
+impl<P> FeeAlgorithm<P> for ExactFee
+where
+    RoundedLinearFee: FeeAlgorithm<P>,
+{
+    fn calculate(&self, part: &P) -> Option<value::Value> {
+        self.minimum
+            .calculate(part)
+            .filter(|minimum| self.fee.0 >= minimum.0)
+            .map(|_| self.fee)
+    }
 }
 
 /// Structure that proofs that certain user agrees with
@@ -1224,6 +3339,7 @@ pub struct Witness(tx::Witness);
 #[wasm_bindgen]
 impl Witness {
     /// Generate Witness for an utxo-based transaction Input
+    /// the genesis hash can be obtained with `Block.genesis_hash` or `Hash.from_hex`
     pub fn for_utxo(
         genesis_hash: Hash,
         transaction_id: TransactionSignDataHash,
@@ -1238,6 +3354,7 @@ impl Witness {
 
     /// Generate Witness for an account based transaction Input
     /// the account-spending-counter should be incremented on each transaction from this account
+    /// the genesis hash can be obtained with `Block.genesis_hash` or `Hash.from_hex`
     pub fn for_account(
         genesis_hash: Hash,
         transaction_id: TransactionSignDataHash,
@@ -1252,20 +3369,178 @@ impl Witness {
         ))
     }
 
+    /// Same as `for_account`, but fails if the spending counter is not the expected one
+    /// (the one required by the current state of the account), as the node would reject
+    /// the transaction anyway
+    pub fn for_account_checked(
+        genesis_hash: Hash,
+        transaction_id: TransactionSignDataHash,
+        secret_key: PrivateKey,
+        account_spending_counter: SpendingCounter,
+        expected_spending_counter: SpendingCounter,
+    ) -> Result<Witness, JsValue> {
+        if account_spending_counter.0 != expected_spending_counter.0 {
+            return Err(JsValue::from_str(&format!(
+                "Invalid spending counter. Found {}, expected: {}",
+                u32::from(account_spending_counter.0),
+                u32::from(expected_spending_counter.0)
+            )));
+        }
+        Ok(Witness::for_account(
+            genesis_hash,
+            transaction_id,
+            secret_key,
+            account_spending_counter,
+        ))
+    }
+
     /// Get string representation
     pub fn to_bech32(&self) -> Result<String, JsValue> {
-        let bytes = self
-            .0
-            .serialize_as_vec()
-            .map_err(|error| JsValue::from_str(&format!("{}", error)))?;
+        bech32::encode("witness", self.as_bytes()?.to_base32())
+            .map_err(|error| JsValue::from_str(&format!("{}", error)))
+    }
 
-        Bech32::new("witness".to_string(), bytes.to_base32())
-            .map(|bech32| bech32.to_string())
+    pub fn as_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        self.0
+            .serialize_as_vec()
             .map_err(|error| JsValue::from_str(&format!("{}", error)))
     }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Witness, JsValue> {
+        witness_from_bytes(bytes).map(Witness)
+    }
+
+    /// Get witness from its bech32 representation (with the `witness` prefix),
+    /// as given by `to_bech32`
+    pub fn from_bech32(bech32_str: &str) -> Result<Witness, JsValue> {
+        witness_from_bech32(bech32_str).map(Witness)
+    }
+}
+
+fn witness_from_bech32(bech32_str: &str) -> Result<tx::Witness, JsValue> {
+    let (hrp, data) =
+        bech32::decode(bech32_str).map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+    if hrp != "witness" {
+        return Err(JsValue::from_str(&format!(
+            "Invalid prefix. Found {}, expected: witness",
+            hrp
+        )));
+    }
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+    witness_from_bytes(&bytes)
+}
+
+fn witness_from_bytes(bytes: &[u8]) -> Result<tx::Witness, JsValue> {
+    let mut buf = ReadBuf::from(bytes);
+    let witness = tx::Witness::read(&mut buf)
+        .map_err(|e| JsValue::from_str(&format!("Malformed witness: {}", e)))?;
+    buf.expect_end()
+        .map_err(|e| JsValue::from_str(&format!("Malformed witness: {}", e)))?;
+    Ok(witness)
+}
+
+/// Helper for creating many witnesses for the same blockchain, so the
+/// genesis hash is given only once
+/// ```javascript
+/// const witnessBuilder = new WitnessBuilder(Hash.from_hex(genesisHashString));
+/// const witness = witnessBuilder.for_account(
+///   finalizer.get_txid(),
+///   inputAccountPrivateKey,
+///   SpendingCounter.zero()
+/// );
+/// ```
+#[wasm_bindgen]
+pub struct WitnessBuilder(key::Hash);
+
+#[wasm_bindgen]
+impl WitnessBuilder {
+    #[wasm_bindgen(constructor)]
+    pub fn new(genesis_hash: Hash) -> WitnessBuilder {
+        WitnessBuilder(genesis_hash.0)
+    }
+
+    /// Generate Witness for an utxo-based transaction Input
+    pub fn for_utxo(
+        &self,
+        transaction_id: TransactionSignDataHash,
+        secret_key: PrivateKey,
+    ) -> Witness {
+        Witness::for_utxo(self.0.into(), transaction_id, secret_key)
+    }
+
+    /// Generate Witness for an account based transaction Input
+    /// the account-spending-counter should be incremented on each transaction from this account
+    pub fn for_account(
+        &self,
+        transaction_id: TransactionSignDataHash,
+        secret_key: PrivateKey,
+        account_spending_counter: SpendingCounter,
+    ) -> Witness {
+        Witness::for_account(
+            self.0.into(),
+            transaction_id,
+            secret_key,
+            account_spending_counter,
+        )
+    }
+}
+
+/// Spending from an account, keeping together the input and the data needed for its witness
+/// (the account key and spending counter) so they can't get out of sync
+/// ```javascript
+/// const spend = new AccountSpend(accountPrivateKey, Value.from_str('1000'), SpendingCounter.zero());
+/// txbuilder.add_input(spend.input());
+/// // ...
+/// finalizer.set_witness(0, spend.witness(Hash.from_hex(genesisHashString), finalizer.get_txid()));
+/// ```
+#[wasm_bindgen]
+pub struct AccountSpend {
+    private_key: PrivateKey,
+    value: value::Value,
+    spending_counter: account::SpendingCounter,
+}
+
+#[wasm_bindgen]
+impl AccountSpend {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        private_key: PrivateKey,
+        value: Value,
+        spending_counter: SpendingCounter,
+    ) -> AccountSpend {
+        AccountSpend {
+            private_key,
+            value: value.0,
+            spending_counter: spending_counter.0,
+        }
+    }
+
+    /// Get the input spending the value from the account of the private key
+    pub fn input(&self) -> Input {
+        let account = Account::from_public_key(self.private_key.to_public());
+        Input(
+            tx::Input::from_account(account.0, self.value),
+            Some(self.spending_counter),
+        )
+    }
+
+    /// Get the witness of the input for the given transaction
+    pub fn witness(
+        &self,
+        genesis_hash: &Hash,
+        transaction_id: &TransactionSignDataHash,
+    ) -> Witness {
+        Witness(tx::Witness::new_account(
+            &genesis_hash.0,
+            &transaction_id.0,
+            &self.spending_counter,
+            &self.private_key.0,
+        ))
+    }
 }
 
 #[wasm_bindgen]
+#[derive(Clone)]
 pub struct SpendingCounter(account::SpendingCounter);
 
 impl From<account::SpendingCounter> for SpendingCounter {
@@ -1280,6 +3555,11 @@ impl From<account::SpendingCounter> for SpendingCounter {
 /// the counter is incremented. A matching counter
 /// needs to be used in the spending phase to make
 /// sure we have non-replayability of a transaction.
+///
+/// The counter is not stored in the transaction, the account witness only signs it,
+/// so the counter used by a signed transaction can't be read back from it. Keep the one
+/// given to `Input.from_account_with_counter` (see `Input.spending_counter`) to know
+/// the counter of the next transaction.
 #[wasm_bindgen]
 impl SpendingCounter {
     pub fn zero() -> Self {
@@ -1289,6 +3569,46 @@ impl SpendingCounter {
     pub fn from_u32(counter: u32) -> Self {
         account::SpendingCounter::from(counter).into()
     }
+
+    pub fn to_u32(&self) -> u32 {
+        u32::from(self.0)
+    }
+
+    /// The counter to use for the next transaction from the account
+    pub fn increment(&self) -> SpendingCounter {
+        self.increment_by(1)
+    }
+
+    /// The counter to use after `n` transactions from the account,
+    /// it wraps around like the counter of the ledger
+    pub fn increment_by(&self, n: u32) -> SpendingCounter {
+        SpendingCounter::from_u32(self.to_u32().wrapping_add(n))
+    }
+}
+
+/// Spending counters of the inputs of a transaction, in the same order as the inputs
+/// (see `GeneratedTransaction.validate`)
+#[wasm_bindgen]
+pub struct SpendingCounters(Vec<SpendingCounter>);
+
+#[wasm_bindgen]
+impl SpendingCounters {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> SpendingCounters {
+        SpendingCounters(vec![])
+    }
+
+    pub fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> SpendingCounter {
+        self.0[index].clone()
+    }
+
+    pub fn add(&mut self, spending_counter: SpendingCounter) {
+        self.0.push(spending_counter);
+    }
 }
 
 /// All possible messages recordable in the Block content
@@ -1332,6 +3652,25 @@ impl Fragment {
             .map_err(|error| JsValue::from_str(&format!("{}", error)))
     }
 
+    /// Basic client side check of whether the fragment could be accepted by a node:
+    /// its serialized size must not exceed `max_size`, it can't be a genesis (initial) fragment
+    /// and transactions must have at least one input or output
+    pub fn is_admissible(&self, max_size: usize) -> Result<bool, JsValue> {
+        if self.as_bytes()?.len() > max_size {
+            return Ok(false);
+        }
+        Ok(match &self.0 {
+            chain::fragment::Fragment::Initial(_) => false,
+            chain::fragment::Fragment::Transaction(auth) => {
+                !(auth.transaction.inputs.is_empty() && auth.transaction.outputs.is_empty())
+            }
+            chain::fragment::Fragment::Certificate(auth) => {
+                !(auth.transaction.inputs.is_empty() && auth.transaction.outputs.is_empty())
+            }
+            _ => true,
+        })
+    }
+
     pub fn is_initial(&self) -> bool {
         match self.0 {
             chain::fragment::Fragment::Initial(_) => true,
@@ -1406,6 +3745,19 @@ impl Block {
         self.0.parent_id().into()
     }
 
+    /// Get the hash of this block as a `Hash`. When called on the genesis block (block 0)
+    /// this is the genesis hash needed for creating witnesses.
+    ///
+    /// The genesis configuration has no network magic, the genesis hash is what identifies
+    /// the network: every witness signs it, so transactions can't be replayed in other networks
+    /// ```javascript
+    /// const genesisHash = Block.from_bytes(block0Bytes).genesis_hash();
+    /// const witness = Witness.for_utxo(genesisHash, txid, privateKey);
+    /// ```
+    pub fn genesis_hash(&self) -> Hash {
+        self.0.id().into()
+    }
+
     ///This involves copying all the messages
     pub fn fragments(&self) -> Fragments {
         self.0
@@ -1414,6 +3766,98 @@ impl Block {
             .collect::<Vec<Fragment>>()
             .into()
     }
+
+    /// Call the given function with each of the fragments of the block, one at a time,
+    /// instead of copying all of them at once as `fragments` does.
+    /// The iteration stops if the callback throws, and the error is rethrown
+    /// ```javascript
+    /// block.for_each_fragment(fragment => {
+    ///   if (fragment.is_transaction()) {
+    ///     processTransaction(fragment.get_transaction());
+    ///   }
+    /// });
+    /// ```
+    pub fn for_each_fragment(&self, callback: &js_sys::Function) -> Result<(), JsValue> {
+        for fragment in self.0.fragments() {
+            callback.call1(&JsValue::NULL, &Fragment::from(fragment.clone()).into())?;
+        }
+        Ok(())
+    }
+
+    /// Get the sum of the fees paid by all the transactions in the block
+    /// (see `Transaction.implied_fee`)
+    pub fn total_fees(&self) -> Result<Value, JsValue> {
+        let fees = self
+            .0
+            .fragments()
+            .filter_map(|fragment| match fragment {
+                chain::fragment::Fragment::Transaction(auth) => {
+                    Some(implied_fee(&auth.transaction))
+                }
+                chain::fragment::Fragment::Certificate(auth) => {
+                    Some(implied_fee(&auth.transaction))
+                }
+                _ => None,
+            })
+            .collect::<Result<Vec<value::Value>, value::ValueError>>()
+            .map_err(value_error)?;
+        value::Value::sum(fees.into_iter())
+            .map_err(value_error)
+            .map(Value)
+    }
+
+    /// Get the number of certificates of each type in the block
+    /// ```javascript
+    /// block.certificate_counts();
+    /// // { stakeDelegation: 2, stakePoolRegistration: 1, stakePoolRetirement: 0 }
+    /// ```
+    pub fn certificate_counts(&self) -> Result<JsValue, JsValue> {
+        let mut counts = CertificateCounts::default();
+        for fragment in self.0.fragments() {
+            if let chain::fragment::Fragment::Certificate(auth) = fragment {
+                match auth.transaction.extra.content {
+                    certificate::CertificateContent::StakeDelegation(_) => {
+                        counts.stake_delegation += 1
+                    }
+                    certificate::CertificateContent::StakePoolRegistration(_) => {
+                        counts.stake_pool_registration += 1
+                    }
+                    certificate::CertificateContent::StakePoolRetirement(_) => {
+                        counts.stake_pool_retirement += 1
+                    }
+                }
+            }
+        }
+        JsValue::from_serde(&counts).map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
+
+    /// Get the bech32 representation (with the given prefix) of every distinct address
+    /// receiving an output in the block transactions, in order of appearance
+    pub fn output_addresses(&self, prefix: &str) -> Result<Vec<JsValue>, JsValue> {
+        validate_address_prefix(prefix)?;
+        let mut seen = std::collections::BTreeSet::new();
+        Ok(self
+            .transaction_outputs()
+            .map(|output| {
+                format!(
+                    "{}",
+                    chain_addr::AddressReadable::from_address(prefix, &output.address)
+                )
+            })
+            .filter(|address| seen.insert(address.clone()))
+            .map(|address| JsValue::from_str(&address))
+            .collect())
+    }
+}
+
+impl Block {
+    fn transaction_outputs(&self) -> impl Iterator<Item = &tx::Output<chain_addr::Address>> {
+        self.0.fragments().flat_map(|fragment| match fragment {
+            chain::fragment::Fragment::Transaction(auth) => auth.transaction.outputs.iter(),
+            chain::fragment::Fragment::Certificate(auth) => auth.transaction.outputs.iter(),
+            _ => [].iter(),
+        })
+    }
 }
 
 #[wasm_bindgen]
@@ -1473,6 +3917,267 @@ pub fn uint8array_to_hex(input: JsValue) -> Result<String, JsValue> {
     String::from_utf8(v).map_err(|e| JsValue::from_str(&format!("{}", e)))
 }
 
+/// Get the id of the transaction (the hash of its sign data) encoded in hexadecimal,
+/// this is the value signed by the witnesses
+/// ```javascript
+/// transaction_txid_hex(txbuilder.unchecked_finalize());
+/// ```
+#[wasm_bindgen]
+pub fn transaction_txid_hex(tx: &Transaction) -> String {
+    format!("{}", tx.id().0)
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum InputSpec {
+    Utxo {
+        fragment_id: String,
+        output_index: u8,
+        value: String,
+    },
+    Account {
+        address: String,
+        value: String,
+    },
+}
+
+#[derive(serde::Deserialize)]
+struct OutputSpec {
+    address: String,
+    value: String,
+}
+
+#[derive(serde::Deserialize)]
+struct TransactionSpec {
+    inputs: Vec<InputSpec>,
+    outputs: Vec<OutputSpec>,
+    #[serde(default)]
+    certificate: Option<String>,
+}
+
+/// Build and finalize a transaction described by a JSON object, values are given as strings
+/// (as in `Value.from_str`), fragment ids in hexadecimal and the certificate in bech32
+/// ```javascript
+/// const transaction = build_transaction_from_json(
+///   {
+///     inputs: [
+///       { utxo: { fragment_id: fragmentIdHex, output_index: 0, value: '1000' } },
+///       { account: { address: accountAddress, value: '500' } }
+///     ],
+///     outputs: [{ address: outputAddress, value: '1200' }],
+///     certificate: 'cert1...' // optional
+///   },
+///   feeAlgorithm,
+///   OutputPolicy.one(changeAddress)
+/// );
+/// ```
+#[wasm_bindgen]
+pub fn build_transaction_from_json(
+    spec: JsValue,
+    fee: &Fee,
+    output_policy: OutputPolicy,
+) -> Result<Transaction, JsValue> {
+    let spec: TransactionSpec = spec
+        .into_serde()
+        .map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+
+    let mut txbuilder = TransactionBuilder::new();
+    for input in spec.inputs {
+        let input = match input {
+            InputSpec::Utxo {
+                fragment_id,
+                output_index,
+                value,
+            } => {
+                let fragment_id = chain::fragment::FragmentId::from_str(&fragment_id)
+                    .map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+                let utxo_pointer =
+                    UtxoPointer::new(fragment_id.into(), output_index, Value::from_str(&value)?);
+                Input::from_utxo(&utxo_pointer)
+            }
+            InputSpec::Account { address, value } => {
+                let account = Account::from_address(&Address::from_string(&address)?)?;
+                Input::from_account(&account, Value::from_str(&value)?)
+            }
+        };
+        txbuilder.add_input(input);
+    }
+    for output in spec.outputs {
+        txbuilder.add_output(
+            Address::from_string(&output.address)?,
+            Value::from_str(&output.value)?,
+        );
+    }
+    if let Some(certificate) = spec.certificate {
+        txbuilder.set_certificate(certificate_from_bech32(&certificate)?.into())?;
+    }
+    txbuilder.finalize(fee, output_policy)
+}
+
+#[derive(Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CertificateCounts {
+    stake_delegation: usize,
+    stake_pool_registration: usize,
+    stake_pool_retirement: usize,
+}
+
+#[derive(serde::Serialize)]
+struct Bech32Info {
+    hrp: String,
+    data_length: usize,
+}
+
+/// Check the bech32 checksum of the given string and get its human readable part
+/// and the length (in bytes) of the encoded data, without parsing the payload.
+/// ```javascript
+/// inspect_bech32('ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2');
+/// // { hrp: 'ed25519_pk', data_length: 32 }
+/// ```
+#[wasm_bindgen]
+pub fn inspect_bech32(s: &str) -> Result<JsValue, JsValue> {
+    let (hrp, data) = bech32::decode(s).map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+    let info = Bech32Info {
+        hrp,
+        // every u5 carries 5 bits, the padding bits of the last one are discarded
+        data_length: data.len() * 5 / 8,
+    };
+    JsValue::from_serde(&info).map_err(|e| JsValue::from_str(&format!("{}", e)))
+}
+
+/// Parse any of the bech32 encoded objects of this library, the type is chosen by the
+/// human readable part of the string: public keys, private keys, certificates and witnesses
+/// have a fixed prefix, addresses use "ca" (production) or "ta" (test). Fails for any other prefix.
+/// Returns an object with the name of the type and the parsed object
+/// ```javascript
+/// const parsed = parse_bech32('ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2');
+/// // { type: 'PublicKey', value: PublicKey }
+/// ```
+#[wasm_bindgen]
+pub fn parse_bech32(s: &str) -> Result<JsValue, JsValue> {
+    let (hrp, _) = bech32::decode(s).map_err(|e| JsValue::from_str(&format!("{}", e)))?;
+    let (type_name, value): (&str, JsValue) = match hrp.as_str() {
+        "ed25519_pk" => ("PublicKey", PublicKey::from_bech32(s)?.into()),
+        "ed25519_sk" | "ed25519e_sk" => ("PrivateKey", PrivateKey::from_bech32(s)?.into()),
+        "cert" => (
+            "Certificate",
+            Certificate::from(certificate_from_bech32(s)?).into(),
+        ),
+        "witness" => ("Witness", Witness(witness_from_bech32(s)?).into()),
+        "ca" | "ta" => ("Address", Address::from_string(s)?.into()),
+        hrp => {
+            return Err(JsValue::from_str(&format!(
+                "Unknown bech32 prefix: {}",
+                hrp
+            )))
+        }
+    };
+    let parsed = js_sys::Object::new();
+    js_sys::Reflect::set(
+        &parsed,
+        &JsValue::from_str("type"),
+        &JsValue::from_str(type_name),
+    )?;
+    js_sys::Reflect::set(&parsed, &JsValue::from_str("value"), &value)?;
+    Ok(parsed.into())
+}
+
+/// Get the single address (in bech32, with the given prefix) of the key derived from
+/// the seed with `PrivateKey.from_seed`
+/// ```javascript
+/// const address = address_from_seed(seed, AddressDiscrimination.Test, 'ta');
+/// ```
+#[wasm_bindgen]
+pub fn address_from_seed(
+    seed: &[u8],
+    discrimination: AddressDiscrimination,
+    prefix: &str,
+) -> Result<String, JsValue> {
+    let public_key = PrivateKey::from_seed(seed)?.to_public();
+    Address::single_from_public_key(public_key, discrimination).to_string(prefix)
+}
+
+/// Check if the given bech32 string (an address) uses the given prefix, without decoding the
+/// address itself. Returns false if the string is not valid bech32
+/// ```javascript
+/// address_matches_prefix('ca1q5nr5pvt9e5p009strshxndrsx5etcentslp2rwj6csm8sfk24a2w3swacn', 'ca');
+/// // true
+/// ```
+#[wasm_bindgen]
+pub fn address_matches_prefix(s: &str, prefix: &str) -> bool {
+    bech32::decode(s)
+        .map(|(hrp, _)| hrp == prefix)
+        .unwrap_or(false)
+}
+
+/// Check if the given string is a valid bech32 address (with any prefix), this is
+/// the same check performed by `Address.from_string`
+#[wasm_bindgen]
+pub fn is_valid_address(s: &str) -> bool {
+    chain_addr::AddressReadable::from_string_anyprefix(s).is_ok()
+}
+
+/// Compute the id of the stake pool registered with the given information, this is the
+/// same as `new StakePoolInfo(serial, owners, kes_public_key, vrf_public_key).id()`.
+/// The id is the hash of the serialized registration, so the information is assembled
+/// anyway, but it is dropped before returning
+#[wasm_bindgen]
+pub fn compute_pool_id(
+    serial: U128,
+    owners: PublicKeys,
+    kes_public_key: KesPublicKey,
+    vrf_public_key: VrfPublicKey,
+) -> StakePoolId {
+    StakePoolInfo::new(serial, owners, kes_public_key, vrf_public_key).id()
+}
+
+/// Get the absolute slot number (counting from the start of the blockchain) of the given
+/// slot in the given epoch. Fails if there are no slots per epoch or if the slot is not
+/// within the epoch
+#[wasm_bindgen]
+pub fn absolute_slot(epoch: u32, slot: u32, slots_per_epoch: u32) -> Result<u64, JsValue> {
+    if slots_per_epoch == 0 {
+        return Err(JsValue::from_str(
+            "slots per epoch must be greater than zero",
+        ));
+    }
+    if slot >= slots_per_epoch {
+        return Err(JsValue::from_str(&format!(
+            "Invalid slot. Found {}, expected: less than {}",
+            slot, slots_per_epoch
+        )));
+    }
+    Ok(u64::from(epoch) * u64::from(slots_per_epoch) + u64::from(slot))
+}
+
+#[derive(serde::Serialize)]
+struct EpochSlot {
+    epoch: u32,
+    slot: u32,
+}
+
+/// Split an absolute slot number into its epoch and the slot within that epoch
+/// ```javascript
+/// slot_to_epoch(absolute_slot(3, 10, 100), 100);
+/// // { epoch: 3, slot: 10 }
+/// ```
+#[wasm_bindgen]
+pub fn slot_to_epoch(absolute: u64, slots_per_epoch: u32) -> Result<JsValue, JsValue> {
+    if slots_per_epoch == 0 {
+        return Err(JsValue::from_str(
+            "slots per epoch must be greater than zero",
+        ));
+    }
+    let slots_per_epoch = u64::from(slots_per_epoch);
+    let epoch = u32::try_from(absolute / slots_per_epoch)
+        .map_err(|_| JsValue::from_str("Epoch out of range"))?;
+    let epoch_slot = EpochSlot {
+        epoch,
+        slot: (absolute % slots_per_epoch) as u32,
+    };
+    JsValue::from_serde(&epoch_slot).map_err(|e| JsValue::from_str(&format!("{}", e)))
+}
+
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
 // allocator.
 #[cfg(feature = "wee_alloc")]
@@ -35,6 +35,12 @@ it('generates certificate', async () => {
   expect(stakePoolInfo.id().to_string()).to.eql(
     'b86471bd72599a45a90fe2cdf4bdc0cc8a8c9af9e87521b64e821ca7d99c2993'
   );
+  expect(stakePoolInfo.kes_bech32()).to.eql(
+    'kes25519-12-pk1s6pr56t6uzkmgdqs2krrh0tw9yyvdwfkzk7e90nsfxg08wqxg6qs9nuu5s'
+  );
+  expect(stakePoolInfo.vrf_bech32()).to.eql(
+    'vrf_pk1fz79zuzm2k8agqs7a5fgdpzprgpnzh58jhz35wjftd0km8dagfdqs8e08k'
+  );
   const certificate = Certificate.stake_pool_registration(stakePoolInfo);
   certificate.sign(
     PrivateKey.from_bech32(
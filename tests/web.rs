@@ -4,6 +4,7 @@
 
 extern crate wasm_bindgen_test;
 use js_chain_libs::*;
+use wasm_bindgen::JsValue;
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -49,6 +50,40 @@ fn mock_builder(input: u64, output: u64) -> TransactionBuilder {
     txbuilder
 }
 
+fn mock_generated_transaction(input: u64, output: u64) -> GeneratedTransaction {
+    let mut finalizer = TransactionFinalizer::new(mock_builder(input, output).unchecked_finalize());
+    let key = PrivateKey::from_bech32("ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef").unwrap();
+    let witness = Witness::for_utxo(Hash::from_bytes(&[0]), finalizer.get_txid(), key);
+    finalizer.set_witness(0, witness).unwrap();
+    finalizer.build().unwrap()
+}
+
+fn mock_transaction_fragment(input: u64, output: u64) -> Fragment {
+    Fragment::from_generated_transaction(mock_generated_transaction(input, output))
+}
+
+/// Build a genesis block (no leader proof) with the given fragments as contents
+fn mock_block(fragments: Vec<Fragment>) -> Block {
+    let mut contents = Vec::new();
+    for fragment in fragments {
+        contents.extend(fragment.as_bytes().unwrap());
+    }
+    let mut header = Vec::new();
+    // block version: genesis
+    header.extend(&0u16.to_be_bytes());
+    header.extend(&(contents.len() as u32).to_be_bytes());
+    // block date (epoch, slot) and chain length
+    header.extend(&[0u8; 12]);
+    // contents hash and parent hash
+    header.extend(&[0u8; 64]);
+
+    let mut bytes = Vec::new();
+    bytes.extend(&(header.len() as u16).to_be_bytes());
+    bytes.extend(header);
+    bytes.extend(contents);
+    Block::from_bytes(js_sys::Uint8Array::from(&bytes[..])).unwrap()
+}
+
 #[wasm_bindgen_test]
 fn transaction_builder_balance() {
     let txbuilder = mock_builder(32, 20);
@@ -118,7 +153,7 @@ fn account_address_from_public_key() {
     let discriminant = AddressDiscrimination::Test;
     let address = Address::account_from_public_key(public_key, discriminant);
     assert_eq!(
-        address.to_string("ta"),
+        address.to_string("ta").unwrap(),
         "ta1sk6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgjm9vc4c"
     );
 }
@@ -132,7 +167,7 @@ fn single_address_from_public_key() {
     let discriminant = AddressDiscrimination::Test;
     let address = Address::single_from_public_key(public_key, discriminant);
     assert_eq!(
-        address.to_string("ta"),
+        address.to_string("ta").unwrap(),
         "ta1sw6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgj44fgl3"
     );
 }
@@ -150,11 +185,44 @@ fn delegation_address_from_public_key() {
     let discriminant = AddressDiscrimination::Test;
     let address = Address::delegation_from_public_key(public_key, account_key, discriminant);
     assert_eq!(
-        address.to_string("ta"),
+        address.to_string("ta").unwrap(),
         "ta1sj6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgnj78ende550w5njvwzyr20q6rypdea597uu3jnwfltljddl59cseaq7yn9"
     );
 }
 
+fn js_field(object: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(object, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn inspect_bech32_group_address() {
+    let info = inspect_bech32("ta1sj6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgnj78ende550w5njvwzyr20q6rypdea597uu3jnwfltljddl59cseaq7yn9").unwrap();
+    assert_eq!(js_field(&info, "hrp").as_string().unwrap(), "ta");
+    assert_eq!(js_field(&info, "data_length").as_f64().unwrap(), 65.0);
+
+    let info =
+        inspect_bech32("ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8")
+            .unwrap();
+    assert_eq!(js_field(&info, "hrp").as_string().unwrap(), "ed25519_pk");
+    assert_eq!(js_field(&info, "data_length").as_f64().unwrap(), 32.0);
+
+    assert!(inspect_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs9"
+    )
+    .is_err());
+}
+
+#[wasm_bindgen_test]
+fn address_matches_prefix_group_address() {
+    let group = "ta1sj6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgnj78ende550w5njvwzyr20q6rypdea597uu3jnwfltljddl59cseaq7yn9";
+    assert!(address_matches_prefix(group, "ta"));
+    assert!(!address_matches_prefix(group, "ca"));
+
+    let single = "ta1sw6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgj44fgl3";
+    assert!(address_matches_prefix(single, "ta"));
+    assert!(!address_matches_prefix("not bech32", "ta"));
+}
+
 #[wasm_bindgen_test]
 fn generate_private_key_ed25519_normal() {
     let key = PrivateKey::generate_ed25519().unwrap();
@@ -166,3 +234,1975 @@ fn generate_private_key_ed25519_extended() {
     let key = PrivateKey::generate_ed25519extended().unwrap();
     assert!(key.to_bech32().starts_with("ed25519e_"));
 }
+
+#[wasm_bindgen_test]
+fn generated_transaction_bytes_round_trip() {
+    let txbuilder = mock_builder(10, 5);
+    let mut finalizer = TransactionFinalizer::new(txbuilder.unchecked_finalize());
+    let genesis_hash = Hash::from_bytes(&[0]);
+    let txid = finalizer.get_txid();
+    let key = PrivateKey::from_bech32("ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef").unwrap();
+    finalizer
+        .set_witness(0, Witness::for_utxo(genesis_hash, txid, key))
+        .unwrap();
+    let signed = finalizer.build().unwrap();
+
+    let bytes = signed.as_bytes().unwrap();
+    let decoded = GeneratedTransaction::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.id().as_bytes(), signed.id().as_bytes());
+    assert_eq!(decoded.as_bytes().unwrap(), bytes);
+}
+
+#[wasm_bindgen_test]
+fn inputs_dedup() {
+    let txbuilder = mock_builder(10, 5);
+    let tx = txbuilder.unchecked_finalize();
+    let input = tx.inputs().get(0);
+    assert!(input.equals(&tx.inputs().get(0)));
+
+    let mut txbuilder = mock_builder(10, 5);
+    txbuilder.add_input(input);
+    let mut inputs = txbuilder.unchecked_finalize().inputs();
+    assert_eq!(inputs.size(), 2);
+    inputs.dedup();
+    assert_eq!(inputs.size(), 1);
+}
+
+#[wasm_bindgen_test]
+fn absolute_slot_epoch_boundaries() {
+    assert_eq!(absolute_slot(0, 0, 100).unwrap(), 0);
+    assert_eq!(absolute_slot(0, 99, 100).unwrap(), 99);
+    assert_eq!(absolute_slot(1, 0, 100).unwrap(), 100);
+    assert_eq!(
+        absolute_slot(u32::max_value(), 99, 100).unwrap(),
+        u64::from(u32::max_value()) * 100 + 99
+    );
+    assert_eq!(
+        absolute_slot(0, 100, 100).unwrap_err().as_string().unwrap(),
+        "Invalid slot. Found 100, expected: less than 100"
+    );
+    assert!(absolute_slot(1, 0, 0).is_err());
+    assert!(slot_to_epoch(100, 0).is_err());
+    assert!(slot_to_epoch(u64::max_value(), 1).is_err());
+}
+
+#[wasm_bindgen_test]
+fn transaction_pays_to() {
+    let tx = mock_builder(10, 5).unchecked_finalize();
+    let output_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let other_address =
+        Address::from_string("ca1q5nr5pvt9e5p009strshxndrsx5etcentslp2rwj6csm8sfk24a2w3swacn")
+            .unwrap();
+    assert!(tx.pays_to(&output_address));
+    assert!(!tx.pays_to(&other_address));
+}
+
+#[wasm_bindgen_test]
+fn transaction_referenced_public_keys_are_distinct() {
+    let key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let account = Account::from_public_key(key.clone());
+    let mut txbuilder = TransactionBuilder::new();
+    txbuilder.add_input(Input::from_account(&account, 10u64.into()));
+    txbuilder.add_input(Input::from_account(&account, 20u64.into()));
+    txbuilder.add_output(
+        Address::account_from_public_key(key.clone(), AddressDiscrimination::Test),
+        30u64.into(),
+    );
+    let keys = txbuilder.unchecked_finalize().referenced_public_keys();
+    assert_eq!(keys.size(), 1);
+    assert_eq!(keys.get(0).as_bytes(), key.as_bytes());
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_finalize_with_fee() {
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+
+    let txbuilder = mock_builder(32, 20);
+    let transaction =
+        txbuilder.finalize_with_fee(&fee_algorithm, 1u64.into(), OutputPolicy::forget());
+    assert!(transaction.is_err());
+
+    let txbuilder = mock_builder(32, 20);
+    let transaction =
+        txbuilder.finalize_with_fee(&fee_algorithm, 5u64.into(), OutputPolicy::forget());
+    assert!(transaction.is_ok());
+
+    let change_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    // 1 input and 2 outputs with the change: the minimum fee is 2 + 3 * 1 = 5
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 1u64.into(), 0u64.into());
+    let transaction = mock_builder(32, 20).finalize_with_fee(
+        &fee_algorithm,
+        4u64.into(),
+        OutputPolicy::one(change_address.clone()),
+    );
+    assert!(transaction.is_err());
+
+    let transaction = mock_builder(32, 20)
+        .finalize_with_fee(
+            &fee_algorithm,
+            7u64.into(),
+            OutputPolicy::one(change_address.clone()),
+        )
+        .unwrap();
+    assert_eq!(transaction.implied_fee().unwrap(), 7u64.into());
+    let outputs = transaction.outputs();
+    assert_eq!(outputs.size(), 2);
+    assert_eq!(outputs.get(1).address(), change_address);
+    assert_eq!(outputs.get(1).value(), (32u64 - 20 - 7).into());
+}
+
+#[wasm_bindgen_test]
+fn transaction_implied_fee() {
+    let tx = mock_builder(32, 20).unchecked_finalize();
+    assert_eq!(tx.implied_fee().unwrap(), 12u64.into());
+
+    let tx = mock_builder(20, 32).unchecked_finalize();
+    assert!(tx.implied_fee().is_err());
+}
+
+#[wasm_bindgen_test]
+fn fee_estimate_from_counts() {
+    let fee_algorithm = Fee::linear_fee(20u64.into(), 5u64.into(), 10u64.into());
+    assert_eq!(fee_algorithm.estimate(3, 2, 1).unwrap(), 55u64.into());
+
+    let fee_algorithm = Fee::linear_fee(0u64.into(), u64::max_value().into(), 0u64.into());
+    assert!(fee_algorithm.estimate(2, 0, 0).is_err());
+}
+
+#[wasm_bindgen_test]
+fn fee_linear_rounded() {
+    let fee_algorithm =
+        Fee::linear_fee_rounded(20u64.into(), 5u64.into(), 10u64.into(), 10u64.into());
+    assert_eq!(fee_algorithm.estimate(1, 2, 0).unwrap(), 40u64.into());
+    assert_eq!(fee_algorithm.estimate(2, 2, 0).unwrap(), 40u64.into());
+    assert_eq!(fee_algorithm.dust_threshold(), 10u64.into());
+
+    let txbuilder = mock_builder(32, 20);
+    let fee_algorithm = Fee::linear_fee_rounded(2u64.into(), 0u64.into(), 0u64.into(), 5u64.into());
+    assert_eq!(txbuilder.estimate_fee(&fee_algorithm).unwrap(), 5u64.into());
+}
+
+#[wasm_bindgen_test]
+fn fee_dust_threshold() {
+    let fee_algorithm = Fee::linear_fee(20u64.into(), 5u64.into(), 10u64.into());
+    assert_eq!(fee_algorithm.dust_threshold(), 5u64.into());
+}
+
+#[wasm_bindgen_test]
+fn value_bytes_round_trip() {
+    let value = Value::from_str("1234567890123").unwrap();
+    assert_eq!(Value::from_be_bytes(&value.to_be_bytes()).unwrap(), value);
+    assert_eq!(Value::from_le_bytes(&value.to_le_bytes()).unwrap(), value);
+    assert_eq!(
+        Value::from_be_bytes(&[0, 0, 0, 0, 0, 0, 1, 0]).unwrap(),
+        256u64.into()
+    );
+    assert!(Value::from_be_bytes(&[0, 1]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn transaction_finalizer_verify_all() {
+    let tx = mock_builder(10, 5).unchecked_finalize();
+    let mut finalizer = TransactionFinalizer::new(tx);
+    let key = PrivateKey::from_bech32("ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef").unwrap();
+    let mut signers = PublicKeys::new();
+    signers.add(key.to_public());
+
+    let status = finalizer.verify_all(&Hash::from_bytes(&[0]), PublicKeys::new());
+    assert_eq!(status[0].as_string().unwrap(), "missing");
+
+    let witness = Witness::for_utxo(Hash::from_bytes(&[0]), finalizer.get_txid(), key);
+    finalizer.set_witness(0, witness).unwrap();
+
+    let status = finalizer.verify_all(&Hash::from_bytes(&[0]), signers);
+    assert_eq!(status[0].as_string().unwrap(), "valid");
+
+    let mut wrong_signers = PublicKeys::new();
+    wrong_signers.add(PrivateKey::generate_ed25519().unwrap().to_public());
+    let status = finalizer.verify_all(&Hash::from_bytes(&[0]), wrong_signers);
+    assert_eq!(status[0].as_string().unwrap(), "invalid");
+}
+
+#[wasm_bindgen_test]
+fn address_validation() {
+    assert!(is_valid_address(
+        "ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344"
+    ));
+    assert!(!is_valid_address(
+        "ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk345"
+    ));
+}
+
+#[wasm_bindgen_test]
+fn transaction_txid_hex_matches_id() {
+    let tx = mock_builder(10, 5).unchecked_finalize();
+    let txid = TransactionSignDataHash::from_hex(&transaction_txid_hex(&tx)).unwrap();
+    assert_eq!(txid.as_bytes(), tx.id().as_bytes());
+}
+
+#[wasm_bindgen_test]
+fn transaction_id_is_cached() {
+    let tx = mock_builder(10, 5).unchecked_finalize();
+    // cloned before the id is computed
+    let fresh = tx.clone();
+    let id = tx.id().as_bytes();
+    assert_eq!(tx.id().as_bytes(), id);
+    // cloned with the cached id
+    let cached = tx.clone();
+    assert_eq!(cached.id().as_bytes(), id);
+    assert_eq!(fresh.id().as_bytes(), id);
+
+    let other = mock_builder(10, 4).unchecked_finalize();
+    assert_ne!(other.id().as_bytes(), id);
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_bump_fee() {
+    let change_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let tx = mock_builder(32, 20).unchecked_finalize();
+    let bumped = TransactionBuilder::from_transaction_bump_fee(tx, 5u64.into(), change_address)
+        .unwrap()
+        .unchecked_finalize();
+    assert_eq!(bumped.outputs().get(0).value(), 15u64.into());
+    assert_eq!(bumped.implied_fee().unwrap(), 17u64.into());
+}
+
+#[wasm_bindgen_test]
+fn address_to_string_invalid_prefix() {
+    let address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    assert!(address.to_string("").is_err());
+    assert!(address.to_string("CA").is_err());
+    assert!(address.to_string("c a").is_err());
+    assert!(address.to_string("ca").is_ok());
+}
+
+#[wasm_bindgen_test]
+fn block_total_fees() {
+    let block = mock_block(vec![
+        mock_transaction_fragment(32, 20),
+        mock_transaction_fragment(10, 5),
+    ]);
+    assert_eq!(block.total_fees().unwrap(), 17u64.into());
+
+    assert_eq!(mock_block(vec![]).total_fees().unwrap(), 0u64.into());
+}
+
+#[wasm_bindgen_test]
+fn public_key_from_bytes_rejects_invalid_points() {
+    let mut off_curve = [0u8; 32];
+    off_curve[0] = 2;
+    assert!(PublicKey::from_bytes(&off_curve).is_err());
+
+    // y = 2^255 - 19, which is not reduced
+    let mut non_canonical = [0xffu8; 32];
+    non_canonical[0] = 0xed;
+    non_canonical[31] = 0x7f;
+    assert!(PublicKey::from_bytes(&non_canonical).is_err());
+
+    assert!(PublicKey::from_bytes(&[0u8; 31]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn balance_difference() {
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+    let positive = mock_builder(32, 20).get_balance(&fee_algorithm).unwrap();
+    let negative = mock_builder(20, 32).get_balance(&fee_algorithm).unwrap();
+
+    let difference = positive.difference(&negative).unwrap();
+    assert_eq!(difference.get_sign(), "positive");
+    assert_eq!(difference.get_value(), 24u64.into());
+
+    let difference = negative.difference(&positive).unwrap();
+    assert_eq!(difference.get_sign(), "negative");
+    assert_eq!(difference.get_value(), 24u64.into());
+
+    assert_eq!(positive.difference(&positive).unwrap().get_sign(), "zero");
+}
+
+#[wasm_bindgen_test]
+fn transaction_canonical_equals() {
+    let first_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let second_address =
+        Address::from_string("ca1q5nr5pvt9e5p009strshxndrsx5etcentslp2rwj6csm8sfk24a2w3swacn")
+            .unwrap();
+
+    let mut txbuilder = mock_builder(30, 10);
+    txbuilder.add_output(second_address.clone(), 5u64.into());
+    let tx = txbuilder.unchecked_finalize();
+
+    let mut txbuilder = TransactionBuilder::new();
+    txbuilder.add_input(tx.inputs().get(0));
+    txbuilder.add_output(second_address, 5u64.into());
+    txbuilder.add_output(first_address, 10u64.into());
+    let reordered = txbuilder.unchecked_finalize();
+
+    assert!(tx.canonical_equals(&reordered));
+    assert!(!tx.canonical_equals(&mock_builder(30, 10).unchecked_finalize()));
+}
+
+#[wasm_bindgen_test]
+fn private_key_controls_address() {
+    let key = PrivateKey::generate_ed25519extended().unwrap();
+    let other_key = PrivateKey::generate_ed25519().unwrap();
+    let address = Address::delegation_from_public_key(
+        key.to_public(),
+        other_key.to_public(),
+        AddressDiscrimination::Test,
+    );
+    assert!(key.controls_address(&address));
+    assert!(!other_key.controls_address(&address));
+}
+
+#[wasm_bindgen_test]
+fn address_from_seed_is_deterministic() {
+    let seed = [7u8; 32];
+    let address = address_from_seed(&seed, AddressDiscrimination::Test, "ta").unwrap();
+    assert_eq!(
+        address,
+        address_from_seed(&seed, AddressDiscrimination::Test, "ta").unwrap()
+    );
+    assert_ne!(
+        address,
+        address_from_seed(&[8u8; 32], AddressDiscrimination::Test, "ta").unwrap()
+    );
+
+    let key = PrivateKey::from_seed(&seed).unwrap();
+    let expected = Address::single_from_public_key(key.to_public(), AddressDiscrimination::Test);
+    assert_eq!(address, expected.to_string("ta").unwrap());
+}
+
+#[wasm_bindgen_test]
+fn private_key_from_short_seed() {
+    assert!(PrivateKey::from_seed(&[]).is_err());
+    assert!(PrivateKey::from_seed(&[7u8]).is_err());
+    assert!(PrivateKey::from_seed(&[7u8; 31]).is_err());
+    assert!(PrivateKey::from_seed(&[7u8; 32]).is_ok());
+    assert!(address_from_seed(&[7u8; 16], AddressDiscrimination::Test, "ta").is_err());
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_finalize_merge_change() {
+    let output_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+
+    let transaction = mock_builder(32, 20)
+        .finalize(
+            &fee_algorithm,
+            OutputPolicy::merge_into_existing(output_address),
+        )
+        .unwrap();
+    assert_eq!(transaction.outputs().size(), 1);
+    assert_eq!(transaction.outputs().get(0).value(), 30u64.into());
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_split_outputs() {
+    let output_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let fee_algorithm = Fee::linear_fee(1u64.into(), 0u64.into(), 0u64.into());
+
+    let mut txbuilder = TransactionBuilder::new();
+    for index in 0..3u8 {
+        let utxopointer = UtxoPointer::new(FragmentId::from_bytes(&[index]), 0, 10u64.into());
+        txbuilder.add_input(Input::from_utxo(&utxopointer));
+    }
+    for _ in 0..3 {
+        txbuilder.add_output(output_address.clone(), 8u64.into());
+    }
+
+    let builders = txbuilder.split_outputs(2, &fee_algorithm).unwrap();
+    assert_eq!(builders.size(), 2);
+    let first = builders.get(0).unchecked_finalize();
+    assert_eq!(first.outputs().size(), 2);
+    assert_eq!(first.inputs().size(), 2);
+    let second = builders.get(1).unchecked_finalize();
+    assert_eq!(second.outputs().size(), 1);
+    assert_eq!(second.inputs().size(), 1);
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_finalize_with_change_index() {
+    let output_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+
+    let finalized = mock_builder(32, 20)
+        .finalize_with_change_index(&fee_algorithm, OutputPolicy::one(output_address))
+        .unwrap();
+    assert_eq!(finalized.change_index(), Some(1));
+    assert_eq!(finalized.transaction().outputs().size(), 2);
+
+    let finalized = mock_builder(32, 20)
+        .finalize_with_change_index(&fee_algorithm, OutputPolicy::forget())
+        .unwrap();
+    assert_eq!(finalized.change_index(), None);
+}
+
+#[wasm_bindgen_test]
+fn input_and_output_bytes_roundtrip() {
+    let utxopointer = UtxoPointer::new(FragmentId::from_bytes(&[1]), 2, 30u64.into());
+    let input = Input::from_utxo(&utxopointer);
+    let decoded = Input::from_bytes(&input.as_bytes().unwrap()).unwrap();
+    assert!(decoded.equals(&input));
+
+    let output = mock_builder(32, 20).unchecked_finalize().outputs().get(0);
+    let decoded = Output::from_bytes(&output.as_bytes().unwrap()).unwrap();
+    assert_eq!(decoded.value(), 20u64.into());
+    assert_eq!(decoded.as_bytes().unwrap(), output.as_bytes().unwrap());
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_shortfall() {
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 1u64.into(), 0u64.into());
+    // fee of 4 with one input and one output
+    assert_eq!(
+        mock_builder(20, 20).shortfall(&fee_algorithm).unwrap(),
+        5u64.into()
+    );
+    assert_eq!(
+        mock_builder(30, 20).shortfall(&fee_algorithm).unwrap(),
+        0u64.into()
+    );
+}
+
+#[wasm_bindgen_test]
+fn transaction_sign_data_bytes_hash_to_id() {
+    let tx = mock_builder(32, 20).unchecked_finalize();
+    let mut digest = [0u8; 32];
+    cryptoxide::blake2b::Blake2b::blake2b(&mut digest, &tx.sign_data_bytes().unwrap(), &[]);
+    assert_eq!(digest.to_vec(), tx.id().as_bytes());
+}
+
+#[wasm_bindgen_test]
+fn value_fits_in_f64_safe() {
+    let max_safe_integer = (1u64 << 53) - 1;
+    assert!(Value::from(max_safe_integer).fits_in_f64_safe());
+    assert!(!Value::from(max_safe_integer + 1).fits_in_f64_safe());
+}
+
+#[wasm_bindgen_test]
+fn transaction_empty() {
+    let tx = Transaction::empty();
+    assert_eq!(tx.inputs().size(), 0);
+    assert_eq!(tx.outputs().size(), 0);
+    assert_eq!(tx.certificate_count(), 0);
+}
+
+#[wasm_bindgen_test]
+fn transaction_fee_overpayment() {
+    let output_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 1u64.into(), 0u64.into());
+    let txbuilder = mock_builder(32, 20);
+    let estimated = txbuilder.estimate_fee(&fee_algorithm).unwrap();
+    let tx = txbuilder
+        .finalize(&fee_algorithm, OutputPolicy::one(output_address))
+        .unwrap();
+    assert_eq!(
+        tx.fee_overpayment(&estimated, &fee_algorithm).unwrap(),
+        1u64.into()
+    );
+}
+
+#[wasm_bindgen_test]
+fn private_key_is_weak() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    assert!(!PrivateKey::from_bech32(key).unwrap().is_weak());
+    assert!(PrivateKey::from_normal_bytes(&[0u8; 32]).unwrap().is_weak());
+}
+
+#[wasm_bindgen_test]
+fn address_to_hash_ignores_prefix() {
+    let address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let other_prefix = Address::from_string(&address.to_string("ta").unwrap()).unwrap();
+    assert_eq!(
+        address.to_hash().as_bytes(),
+        other_prefix.to_hash().as_bytes()
+    );
+}
+
+#[wasm_bindgen_test]
+fn account_spend_input() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let private_key = PrivateKey::from_bech32(key).unwrap();
+    let account = Account::from_public_key(private_key.to_public());
+    let spend = AccountSpend::new(private_key, 100u64.into(), SpendingCounter::zero());
+    let input = spend.input();
+    assert_eq!(input.get_type(), "Account");
+    assert!(input.equals(&Input::from_account(&account, 100u64.into())));
+    assert!(input.spending_counter().is_some());
+}
+
+#[wasm_bindgen_test]
+fn address_with_discrimination() {
+    let key = "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2";
+    let public_key = PublicKey::from_bech32(key).unwrap();
+    let test_address =
+        Address::single_from_public_key(public_key.clone(), AddressDiscrimination::Test);
+    let production_address =
+        Address::single_from_public_key(public_key, AddressDiscrimination::Production);
+    assert_eq!(
+        test_address.with_discrimination(AddressDiscrimination::Production),
+        production_address
+    );
+}
+
+#[wasm_bindgen_test]
+fn stake_delegation_certificate_verify_signatures() {
+    let stake_pool_id =
+        StakePoolId::from_hex("541db50349e2bc1a5b1a73939b9d86fc45067117cc930c36afbb6fb0a9329d41")
+            .unwrap();
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let private_key = PrivateKey::from_bech32(key).unwrap();
+    let mut certificate = Certificate::stake_delegation(stake_pool_id, private_key.to_public());
+    assert!(!certificate.verify_signatures().unwrap());
+
+    certificate.sign(PrivateKey::from_bech32(key).unwrap());
+    assert!(certificate.verify_signatures().unwrap());
+}
+
+#[wasm_bindgen_test]
+fn address_payment_key() {
+    let spending_key = PublicKey::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+    )
+    .unwrap();
+    let delegation_key = PublicKey::from_bech32(
+        "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2",
+    )
+    .unwrap();
+    let address = Address::delegation_from_public_key(
+        spending_key.clone(),
+        delegation_key,
+        AddressDiscrimination::Test,
+    );
+    assert_eq!(
+        address.payment_key().unwrap().as_bytes(),
+        spending_key.as_bytes()
+    );
+}
+
+#[wasm_bindgen_test]
+fn certificate_equality() {
+    let stake_pool_id = "541db50349e2bc1a5b1a73939b9d86fc45067117cc930c36afbb6fb0a9329d41";
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let private_key = PrivateKey::from_bech32(key).unwrap();
+    let certificate = Certificate::stake_delegation(
+        StakePoolId::from_hex(stake_pool_id).unwrap(),
+        private_key.to_public(),
+    );
+    let mut signed = Certificate::stake_delegation(
+        StakePoolId::from_hex(stake_pool_id).unwrap(),
+        private_key.to_public(),
+    );
+    signed.sign(private_key);
+    assert!(certificate.content_equals(&signed));
+    assert!(!certificate.equals(&signed));
+}
+
+#[wasm_bindgen_test]
+fn transaction_fee_ratio_basis_points() {
+    let tx = mock_builder(1003, 1000).unchecked_finalize();
+    assert_eq!(tx.fee_ratio_basis_points().unwrap(), 30);
+
+    let tx = mock_builder(10, 0).unchecked_finalize();
+    assert!(tx.fee_ratio_basis_points().is_err());
+}
+
+#[wasm_bindgen_test]
+fn transaction_total_values_overflow() {
+    let mut txbuilder = mock_builder(32, u64::max_value());
+    let output_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    txbuilder.add_output(output_address, 1u64.into());
+    let tx = txbuilder.unchecked_finalize();
+    assert_eq!(tx.total_input_value().unwrap(), 32u64.into());
+    assert_eq!(
+        tx.total_output_value().unwrap_err().as_string().unwrap(),
+        "Value overflow"
+    );
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_finalize_change_like_output() {
+    let change_key = PublicKey::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+    )
+    .unwrap();
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+    let transaction = mock_builder(32, 20)
+        .finalize(
+            &fee_algorithm,
+            OutputPolicy::change_like_output(
+                change_key.clone(),
+                change_key.clone(),
+                AddressDiscrimination::Test,
+            ),
+        )
+        .unwrap();
+    assert_eq!(transaction.outputs().size(), 2);
+    let change = transaction.outputs().get(1);
+    assert_eq!(change.value(), 10u64.into());
+    assert_eq!(
+        change.address(),
+        Address::account_from_public_key(change_key, AddressDiscrimination::Test)
+    );
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_finalize_change_like_group_output() {
+    let change_key = PublicKey::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+    )
+    .unwrap();
+    let wallet_delegation_key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let payment_key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let payee_delegation_key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let mut txbuilder = TransactionBuilder::new();
+    let utxopointer = UtxoPointer::new(FragmentId::from_bytes(&[0]), 0, 32u64.into());
+    txbuilder.add_input(Input::from_utxo(&utxopointer));
+    txbuilder.add_output(
+        Address::delegation_from_public_key(
+            payment_key,
+            payee_delegation_key,
+            AddressDiscrimination::Test,
+        ),
+        20u64.into(),
+    );
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+    let transaction = txbuilder
+        .finalize(
+            &fee_algorithm,
+            OutputPolicy::change_like_output(
+                change_key.clone(),
+                wallet_delegation_key.clone(),
+                AddressDiscrimination::Test,
+            ),
+        )
+        .unwrap();
+    let change = transaction.outputs().get(1);
+    assert_eq!(change.value(), 10u64.into());
+    assert_eq!(
+        change.address(),
+        Address::delegation_from_public_key(
+            change_key,
+            wallet_delegation_key,
+            AddressDiscrimination::Test
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn input_from_output() {
+    let output = mock_builder(32, 20).unchecked_finalize().outputs().get(0);
+    let input = Input::from_output(&output, &FragmentId::from_bytes(&[1]), 0);
+    let utxopointer = UtxoPointer::new(FragmentId::from_bytes(&[1]), 0, 20u64.into());
+    assert!(input.equals(&Input::from_utxo(&utxopointer)));
+}
+
+#[wasm_bindgen_test]
+fn generated_transaction_validate() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let private_key = PrivateKey::from_bech32(key).unwrap();
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+    let tx = mock_builder(32, 20)
+        .finalize(&fee_algorithm, OutputPolicy::forget())
+        .unwrap();
+
+    let mut finalizer = TransactionFinalizer::new(tx);
+    let witness = Witness::for_utxo(Hash::from_bytes(&[0]), finalizer.get_txid(), private_key);
+    finalizer.set_witness(0, witness).unwrap();
+    let signed = finalizer.build().unwrap();
+
+    let mut signers = PublicKeys::new();
+    signers.add(PrivateKey::from_bech32(key).unwrap().to_public());
+    assert!(signed
+        .validate(
+            &fee_algorithm,
+            &Hash::from_bytes(&[0]),
+            signers,
+            SpendingCounters::new()
+        )
+        .is_ok());
+
+    let mut signers = PublicKeys::new();
+    signers.add(PrivateKey::from_bech32(key).unwrap().to_public());
+    assert!(signed
+        .validate(
+            &fee_algorithm,
+            &Hash::from_bytes(&[1]),
+            signers,
+            SpendingCounters::new()
+        )
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn address_kind_and_discrimination() {
+    let key = PublicKey::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+    )
+    .unwrap();
+    let delegation = PublicKey::from_bech32(
+        "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2",
+    )
+    .unwrap();
+
+    let single = Address::single_from_public_key(key.clone(), AddressDiscrimination::Test);
+    assert_eq!(single.kind(), "Single");
+    assert_eq!(single.discrimination(), AddressDiscrimination::Test);
+
+    let group = Address::delegation_from_public_key(
+        key.clone(),
+        delegation,
+        AddressDiscrimination::Production,
+    );
+    assert_eq!(group.kind(), "Group");
+    assert_eq!(group.discrimination(), AddressDiscrimination::Production);
+
+    let account = Address::account_from_public_key(key, AddressDiscrimination::Test);
+    assert_eq!(account.kind(), "Account");
+    assert_eq!(account.discrimination(), AddressDiscrimination::Test);
+}
+
+#[wasm_bindgen_test]
+fn address_spending_and_delegation_keys() {
+    let key = PublicKey::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+    )
+    .unwrap();
+    let delegation = PublicKey::from_bech32(
+        "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2",
+    )
+    .unwrap();
+    let group = Address::delegation_from_public_key(
+        key.clone(),
+        delegation.clone(),
+        AddressDiscrimination::Test,
+    );
+    assert_eq!(group.get_spending_key().unwrap().as_bytes(), key.as_bytes());
+    assert_eq!(
+        group.get_delegation_key().unwrap().as_bytes(),
+        delegation.as_bytes()
+    );
+
+    let single = Address::single_from_public_key(key, AddressDiscrimination::Test);
+    assert!(single.get_delegation_key().is_err());
+}
+
+#[wasm_bindgen_test]
+fn address_bytes_roundtrip() {
+    let key = PublicKey::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+    )
+    .unwrap();
+    let delegation = PublicKey::from_bech32(
+        "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2",
+    )
+    .unwrap();
+    let addresses = vec![
+        Address::single_from_public_key(key.clone(), AddressDiscrimination::Test),
+        Address::delegation_from_public_key(
+            key.clone(),
+            delegation,
+            AddressDiscrimination::Production,
+        ),
+        Address::account_from_public_key(key, AddressDiscrimination::Test),
+    ];
+    for address in addresses {
+        let bytes = address.as_bytes();
+        assert_eq!(Address::from_bytes(&bytes).unwrap(), address);
+        assert!(Address::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+    assert!(Address::from_bytes(&[]).is_err());
+}
+
+fn mock_stake_pool_info(owner: PublicKey) -> StakePoolInfo {
+    let mut owners = PublicKeys::new();
+    owners.add(owner);
+    StakePoolInfo::new(
+        U128::from_str("1010101010").unwrap(),
+        owners,
+        KesPublicKey::from_bech32(
+            "kes25519-12-pk1s6pr56t6uzkmgdqs2krrh0tw9yyvdwfkzk7e90nsfxg08wqxg6qs9nuu5s",
+        )
+        .unwrap(),
+        VrfPublicKey::from_bech32(
+            "vrf_pk1fz79zuzm2k8agqs7a5fgdpzprgpnzh58jhz35wjftd0km8dagfdqs8e08k",
+        )
+        .unwrap(),
+    )
+}
+
+#[wasm_bindgen_test]
+fn stake_pool_retirement_certificate() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let owner = PrivateKey::from_bech32(key).unwrap().to_public();
+    let pool_id = mock_stake_pool_info(owner.clone()).id();
+
+    let mut certificate = Certificate::stake_pool_retirement(pool_id, mock_stake_pool_info(owner));
+    certificate.sign(PrivateKey::from_bech32(key).unwrap());
+    assert!(certificate.verify_signatures().unwrap());
+    assert!(certificate.to_bech32().is_ok());
+}
+
+#[wasm_bindgen_test]
+fn certificate_get_type() {
+    let owner = PublicKey::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+    )
+    .unwrap();
+    let pool_id = mock_stake_pool_info(owner.clone()).id();
+
+    let delegation = Certificate::stake_delegation(pool_id, owner.clone());
+    assert_eq!(delegation.get_type(), "StakeDelegation");
+
+    let registration = Certificate::stake_pool_registration(mock_stake_pool_info(owner.clone()));
+    assert_eq!(registration.get_type(), "StakePoolRegistration");
+
+    let retirement = Certificate::stake_pool_retirement(
+        mock_stake_pool_info(owner.clone()).id(),
+        mock_stake_pool_info(owner),
+    );
+    assert_eq!(retirement.get_type(), "StakePoolRetirement");
+}
+
+#[wasm_bindgen_test]
+fn certificate_bech32_roundtrip() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let owner = PrivateKey::from_bech32(key).unwrap().to_public();
+    let mut certificate = Certificate::stake_pool_registration(mock_stake_pool_info(owner));
+    certificate.sign(PrivateKey::from_bech32(key).unwrap());
+
+    let bech32 = certificate.to_bech32().unwrap();
+    let decoded = Certificate::from_bech32(&bech32).unwrap();
+    assert!(decoded.equals(&certificate));
+    assert_eq!(decoded.to_bech32().unwrap(), bech32);
+
+    let decoded = Certificate::from_bytes(&certificate.as_bytes().unwrap()).unwrap();
+    assert!(decoded.equals(&certificate));
+
+    assert!(Certificate::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8"
+    )
+    .is_err());
+    assert!(Certificate::from_bytes(&[1, 2, 3]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn value_checked_mul_and_div() {
+    let max = Value::from(u64::max_value());
+    assert_eq!(
+        Value::from(6).checked_mul(&Value::from(7)).unwrap(),
+        42u64.into()
+    );
+    assert!(max.checked_mul(&Value::from(2)).is_err());
+    assert_eq!(max.checked_mul(&Value::from(1)).unwrap(), max);
+
+    assert_eq!(
+        Value::from(43).checked_div(&Value::from(7)).unwrap(),
+        6u64.into()
+    );
+    assert!(Value::from(1).checked_div(&Value::from(0)).is_err());
+}
+
+#[wasm_bindgen_test]
+fn value_compare() {
+    let max = Value::from(u64::max_value());
+    let almost_max = Value::from(u64::max_value() - 1);
+    assert_eq!(almost_max.compare(&max), -1);
+    assert_eq!(max.compare(&almost_max), 1);
+    assert_eq!(max.compare(&Value::from(u64::max_value())), 0);
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_get_inputs_and_outputs() {
+    let account = Account::from_public_key(
+        PublicKey::from_bech32(
+            "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+        )
+        .unwrap(),
+    );
+    let output_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_input(Input::from_account(&account, 10u64.into()));
+    txbuilder.add_output(output_address, 15u64.into());
+
+    let inputs = txbuilder.get_inputs();
+    assert_eq!(inputs.size(), 2);
+    assert_eq!(inputs.get(0).get_type(), "Utxo");
+    assert_eq!(inputs.get(1).get_type(), "Account");
+    let outputs = txbuilder.get_outputs();
+    assert_eq!(outputs.size(), 2);
+    assert_eq!(outputs.get(1).value(), 15u64.into());
+
+    let stake_pool_id =
+        StakePoolId::from_hex("541db50349e2bc1a5b1a73939b9d86fc45067117cc930c36afbb6fb0a9329d41")
+            .unwrap();
+    let public_key = PublicKey::from_bech32(
+        "ed25519_pk1ycaqtzewdqtmevzcu9e5mgup4x27xv6u8c2sm5kkyxeuzdj402ns0uny5a",
+    )
+    .unwrap();
+    txbuilder
+        .set_certificate(Certificate::stake_delegation(stake_pool_id, public_key))
+        .unwrap();
+    assert_eq!(txbuilder.get_inputs().size(), 2);
+    assert_eq!(txbuilder.get_outputs().size(), 2);
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_remove_input_and_output() {
+    let output_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let mut txbuilder = mock_builder(32, 1);
+    txbuilder.add_output(output_address.clone(), 2u64.into());
+    txbuilder.add_output(output_address, 3u64.into());
+
+    txbuilder.remove_output(1).unwrap();
+    let outputs = txbuilder.get_outputs();
+    assert_eq!(outputs.size(), 2);
+    assert_eq!(outputs.get(0).value(), 1u64.into());
+    assert_eq!(outputs.get(1).value(), 3u64.into());
+    assert!(txbuilder.remove_output(2).is_err());
+
+    txbuilder.remove_input(0).unwrap();
+    assert_eq!(txbuilder.get_inputs().size(), 0);
+    assert!(txbuilder.remove_input(0).is_err());
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_clear_certificate() {
+    let stake_pool_id =
+        StakePoolId::from_hex("541db50349e2bc1a5b1a73939b9d86fc45067117cc930c36afbb6fb0a9329d41")
+            .unwrap();
+    let public_key = PublicKey::from_bech32(
+        "ed25519_pk1ycaqtzewdqtmevzcu9e5mgup4x27xv6u8c2sm5kkyxeuzdj402ns0uny5a",
+    )
+    .unwrap();
+    let mut txbuilder = mock_builder(30, 20);
+    txbuilder
+        .set_certificate(Certificate::stake_delegation(stake_pool_id, public_key))
+        .unwrap();
+
+    txbuilder.clear_certificate();
+    assert_eq!(txbuilder.get_inputs().size(), 1);
+    assert_eq!(txbuilder.get_outputs().get(0).value(), 20u64.into());
+    assert_eq!(txbuilder.unchecked_finalize().certificate_count(), 0);
+}
+
+#[wasm_bindgen_test]
+fn input_from_utxo_parts() {
+    let utxopointer = UtxoPointer::new(FragmentId::from_bytes(&[1]), 3, 30u64.into());
+    let input = Input::from_utxo_parts(FragmentId::from_bytes(&[1]), 3, 30u64.into());
+    assert!(input.equals(&Input::from_utxo(&utxopointer)));
+}
+
+#[wasm_bindgen_test]
+fn input_bytes_roundtrip_for_each_kind() {
+    let account = Account::from_public_key(
+        PublicKey::from_bech32(
+            "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+        )
+        .unwrap(),
+    );
+    let inputs = vec![
+        Input::from_utxo_parts(FragmentId::from_bytes(&[1]), 2, 30u64.into()),
+        Input::from_account(&account, 40u64.into()),
+    ];
+    for input in inputs {
+        let decoded = Input::from_bytes(&input.as_bytes().unwrap()).unwrap();
+        assert!(decoded.equals(&input));
+        assert_eq!(decoded.get_type(), input.get_type());
+    }
+    assert!(Input::from_bytes(&[0, 1, 2]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn output_new() {
+    let address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let output = Output::new(address.clone(), 25u64.into());
+    assert_eq!(output.address(), address);
+    assert_eq!(output.value(), 25u64.into());
+}
+
+#[wasm_bindgen_test]
+fn utxo_pointer_getters() {
+    let utxopointer = UtxoPointer::new(FragmentId::from_bytes(&[1]), 3, 30u64.into());
+    assert_eq!(
+        utxopointer.fragment_id().as_bytes(),
+        FragmentId::from_bytes(&[1]).as_bytes()
+    );
+    assert_eq!(utxopointer.output_index(), 3);
+    assert_eq!(utxopointer.value(), 30u64.into());
+}
+
+#[wasm_bindgen_test]
+fn public_key_bech32_roundtrip() {
+    let key = "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2";
+    let public_key = PublicKey::from_bech32(key).unwrap();
+    assert_eq!(public_key.to_bech32(), key);
+}
+
+#[wasm_bindgen_test]
+fn public_key_bytes_roundtrip() {
+    let key = "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2";
+    let public_key = PublicKey::from_bech32(key).unwrap();
+    let decoded = PublicKey::from_bytes(&public_key.as_bytes()).unwrap();
+    assert_eq!(decoded.to_bech32(), key);
+    assert!(PublicKey::from_bytes(&[0u8; 33]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn private_key_bytes_roundtrip() {
+    let normal = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let key = PrivateKey::from_bech32(normal).unwrap();
+    let bytes = key.as_bytes();
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(
+        PrivateKey::from_normal_bytes(&bytes).unwrap().to_bech32(),
+        normal
+    );
+    assert!(PrivateKey::from_extended_bytes(&bytes).is_err());
+
+    let extended = "ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef";
+    let key = PrivateKey::from_bech32(extended).unwrap();
+    let bytes = key.as_bytes();
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(
+        PrivateKey::from_extended_bytes(&bytes).unwrap().to_bech32(),
+        extended
+    );
+    assert!(PrivateKey::from_normal_bytes(&bytes).is_err());
+}
+
+#[wasm_bindgen_test]
+fn sign_and_verify_message() {
+    let key = PrivateKey::from_bech32(
+        "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje",
+    )
+    .unwrap();
+    let other_key = PrivateKey::from_bech32("ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef").unwrap();
+    let message = b"off-chain message";
+    let signature = key.sign(message);
+    assert!(key.to_public().verify(message, &signature));
+    assert!(!other_key.to_public().verify(message, &signature));
+    assert!(!key.to_public().verify(b"another message", &signature));
+
+    let decoded = Signature::from_bytes(&signature.as_bytes()).unwrap();
+    assert!(key.to_public().verify(message, &decoded));
+    assert_eq!(decoded.to_bech32(), signature.to_bech32());
+    assert!(Signature::from_bytes(&[0u8; 63]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn spending_counter_to_u32() {
+    assert_eq!(SpendingCounter::from_u32(5).to_u32(), 5);
+    assert_eq!(SpendingCounter::zero().to_u32(), 0);
+}
+
+#[wasm_bindgen_test]
+fn spending_counter_increment() {
+    assert_eq!(
+        SpendingCounter::zero().increment().to_u32(),
+        SpendingCounter::from_u32(1).to_u32()
+    );
+    assert_eq!(SpendingCounter::from_u32(5).increment_by(3).to_u32(), 8);
+}
+
+#[wasm_bindgen_test]
+fn witness_bytes_and_bech32_roundtrip() {
+    let finalizer = TransactionFinalizer::new(mock_builder(10, 5).unchecked_finalize());
+    let key = PrivateKey::from_bech32("ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef").unwrap();
+    let witness = Witness::for_utxo(Hash::from_bytes(&[0]), finalizer.get_txid(), key);
+
+    let bytes = witness.as_bytes().unwrap();
+    assert_eq!(
+        Witness::from_bytes(&bytes).unwrap().as_bytes().unwrap(),
+        bytes
+    );
+
+    let bech32 = witness.to_bech32().unwrap();
+    assert_eq!(
+        Witness::from_bech32(&bech32).unwrap().to_bech32().unwrap(),
+        bech32
+    );
+
+    assert!(Witness::from_bytes(&bytes[1..]).is_err());
+    assert!(Witness::from_bech32(
+        "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2"
+    )
+    .is_err());
+}
+
+#[wasm_bindgen_test]
+fn single_and_multisig_accounts() {
+    let key = "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8";
+    let account = Account::from_public_key(PublicKey::from_bech32(key).unwrap());
+    assert!(!account.is_multisig());
+    let address = account.to_address().unwrap();
+    assert_eq!(
+        address,
+        Address::account_from_public_key(
+            PublicKey::from_bech32(key).unwrap(),
+            AddressDiscrimination::Production
+        )
+    );
+    assert!(!Account::from_address(&address).unwrap().is_multisig());
+
+    let multisig = Account::from_multisig_identifier(&[7u8; 32]).unwrap();
+    assert!(multisig.is_multisig());
+    assert!(multisig.to_address().is_err());
+    assert_eq!(multisig.kind(), "Multisig");
+    assert_eq!(account.kind(), "Single");
+    assert!(Account::from_multisig_identifier(&[7u8; 31]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn multisig_account_input_roundtrip() {
+    let multisig = Account::from_multisig_identifier(&[7u8; 32]).unwrap();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_input(Input::from_account(&multisig, 10u64.into()));
+    let transaction = txbuilder.unchecked_finalize();
+    assert!(transaction.spends_from_account(&multisig));
+
+    assert!(transaction.inputs().get(1).get_account().is_ok());
+}
+
+#[wasm_bindgen_test]
+fn single_account_input_roundtrip() {
+    let key = PublicKey::from_bech32(
+        "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+    )
+    .unwrap();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_input(Input::from_account(
+        &Account::from_public_key(key.clone()),
+        10u64.into(),
+    ));
+    let transaction = txbuilder.unchecked_finalize();
+
+    let account = transaction.inputs().get(1).get_account().unwrap();
+    assert_eq!(account.kind(), "Single");
+    assert!(!account.is_multisig());
+    assert_eq!(
+        account.to_address().unwrap(),
+        Address::account_from_public_key(key, AddressDiscrimination::Production)
+    );
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_keeps_spending_counters() {
+    let account = Account::from_public_key(
+        PublicKey::from_bech32(
+            "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+        )
+        .unwrap(),
+    );
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_input(Input::from_account_with_counter(
+        &account,
+        10u64.into(),
+        SpendingCounter::from_u32(7),
+    ));
+    txbuilder.add_input(Input::from_account_with_counter(
+        &account,
+        5u64.into(),
+        SpendingCounter::from_u32(8),
+    ));
+
+    let inputs = txbuilder.get_inputs();
+    assert!(inputs.get(0).spending_counter().is_none());
+    assert_eq!(inputs.get(1).spending_counter().unwrap().to_u32(), 7);
+
+    txbuilder.remove_input(1).unwrap();
+    let transaction = txbuilder.unchecked_finalize();
+    assert_eq!(
+        transaction
+            .inputs()
+            .get(1)
+            .spending_counter()
+            .unwrap()
+            .to_u32(),
+        8
+    );
+
+    let finalizer = TransactionFinalizer::new(transaction);
+    assert!(finalizer.spending_counter(0).is_none());
+    assert_eq!(finalizer.spending_counter(1).unwrap().to_u32(), 8);
+    assert!(finalizer.spending_counter(2).is_none());
+}
+
+#[wasm_bindgen_test]
+fn transaction_finalizer_verify_all_account_witnesses() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let account = Account::from_public_key(PrivateKey::from_bech32(key).unwrap().to_public());
+    let mut txbuilder = TransactionBuilder::new();
+    txbuilder.add_input(Input::from_account_with_counter(
+        &account,
+        10u64.into(),
+        SpendingCounter::from_u32(3),
+    ));
+    txbuilder.add_input(Input::from_account(&account, 10u64.into()));
+    txbuilder.add_output(account.to_address().unwrap(), 15u64.into());
+    let mut finalizer = TransactionFinalizer::new(txbuilder.unchecked_finalize());
+    let genesis_hash = Hash::from_bytes(&[0]);
+    let txid = finalizer.get_txid().as_bytes();
+
+    let witness = |counter| {
+        Witness::for_account(
+            Hash::from_bytes(&[0]),
+            TransactionSignDataHash::from_bytes(&txid).unwrap(),
+            PrivateKey::from_bech32(key).unwrap(),
+            SpendingCounter::from_u32(counter),
+        )
+    };
+    finalizer.set_witness(0, witness(3)).unwrap();
+    finalizer.set_witness(1, witness(3)).unwrap();
+    let status = finalizer.verify_all(&genesis_hash, PublicKeys::new());
+    assert_eq!(status[0].as_string().unwrap(), "valid");
+    assert_eq!(status[1].as_string().unwrap(), "unverified");
+
+    finalizer.set_witness(0, witness(4)).unwrap();
+    let status = finalizer.verify_all(&genesis_hash, PublicKeys::new());
+    assert_eq!(status[0].as_string().unwrap(), "invalid");
+}
+
+#[wasm_bindgen_test]
+fn generated_transaction_validate_account_witness() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let other_key = "ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef";
+    let account = Account::from_public_key(PrivateKey::from_bech32(key).unwrap().to_public());
+    let fee_algorithm = Fee::linear_fee(0u64.into(), 0u64.into(), 0u64.into());
+    let mut txbuilder = TransactionBuilder::new();
+    txbuilder.add_input(Input::from_account(&account, 10u64.into()));
+    txbuilder.add_output(account.to_address().unwrap(), 10u64.into());
+    let transaction = txbuilder.unchecked_finalize();
+
+    let signed = |signing_key: &str| {
+        let mut finalizer = TransactionFinalizer::new(transaction.clone());
+        let witness = Witness::for_account(
+            Hash::from_bytes(&[0]),
+            finalizer.get_txid(),
+            PrivateKey::from_bech32(signing_key).unwrap(),
+            SpendingCounter::from_u32(3),
+        );
+        finalizer.set_witness(0, witness).unwrap();
+        finalizer.build().unwrap()
+    };
+    let spending_counters = |counter| {
+        let mut spending_counters = SpendingCounters::new();
+        spending_counters.add(SpendingCounter::from_u32(counter));
+        spending_counters
+    };
+    let genesis_hash = Hash::from_bytes(&[0]);
+
+    assert!(signed(key)
+        .validate(
+            &fee_algorithm,
+            &genesis_hash,
+            PublicKeys::new(),
+            spending_counters(3)
+        )
+        .is_ok());
+    // forged witness: signed by another key
+    assert!(signed(other_key)
+        .validate(
+            &fee_algorithm,
+            &genesis_hash,
+            PublicKeys::new(),
+            spending_counters(3)
+        )
+        .is_err());
+    // replayed witness: signed with an old counter
+    assert!(signed(key)
+        .validate(
+            &fee_algorithm,
+            &genesis_hash,
+            PublicKeys::new(),
+            spending_counters(4)
+        )
+        .is_err());
+    assert!(signed(key)
+        .validate(
+            &fee_algorithm,
+            &genesis_hash,
+            PublicKeys::new(),
+            SpendingCounters::new()
+        )
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn verify_transactions_batch() {
+    let key = "ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef";
+    let block = mock_block(vec![
+        mock_transaction_fragment(32, 20),
+        mock_transaction_fragment(10, 5),
+    ]);
+    let signers = |count| {
+        let mut signers = FragmentSigners::new();
+        for _ in 0..count {
+            let mut keys = PublicKeys::new();
+            keys.add(PrivateKey::from_bech32(key).unwrap().to_public());
+            signers.add(keys, SpendingCounters::new());
+        }
+        signers
+    };
+
+    let statuses = verify_transactions(&block.fragments(), &Hash::from_bytes(&[0]), &signers(2));
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[0].as_string().unwrap(), "valid");
+    assert_eq!(statuses[1].as_string().unwrap(), "valid");
+
+    // the input of the second transaction has no signer
+    let statuses = verify_transactions(&block.fragments(), &Hash::from_bytes(&[0]), &signers(1));
+    assert_eq!(statuses[0].as_string().unwrap(), "valid");
+    assert_eq!(statuses[1].as_string().unwrap(), "unverified");
+
+    let statuses = verify_transactions(&block.fragments(), &Hash::from_bytes(&[1]), &signers(2));
+    assert_eq!(statuses[0].as_string().unwrap(), "invalid");
+
+    let other_key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let mut wrong_signers = FragmentSigners::new();
+    let mut keys = PublicKeys::new();
+    keys.add(other_key);
+    wrong_signers.add(keys, SpendingCounters::new());
+    let statuses = verify_transactions(&block.fragments(), &Hash::from_bytes(&[0]), &wrong_signers);
+    assert_eq!(statuses[0].as_string().unwrap(), "invalid");
+    assert_eq!(statuses[1].as_string().unwrap(), "unverified");
+}
+
+fn json(s: &str) -> JsValue {
+    js_sys::JSON::parse(s).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn build_transaction_from_json_roundtrip() {
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+    let fragment_id_hex: String = FragmentId::from_bytes(&[0])
+        .as_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    let spec = format!(
+        r#"{{
+            "inputs": [
+                {{ "utxo": {{ "fragment_id": "{}", "output_index": 0, "value": "32" }} }},
+                {{ "account": {{ "address": "ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344", "value": "10" }} }}
+            ],
+            "outputs": [
+                {{ "address": "ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344", "value": "20" }}
+            ]
+        }}"#,
+        fragment_id_hex
+    );
+    let from_json =
+        build_transaction_from_json(json(&spec), &fee_algorithm, OutputPolicy::forget()).unwrap();
+
+    let address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_input(Input::from_account(
+        &Account::from_address(&address).unwrap(),
+        10u64.into(),
+    ));
+    let expected = txbuilder
+        .finalize(&fee_algorithm, OutputPolicy::forget())
+        .unwrap();
+
+    assert_eq!(from_json.id().as_bytes(), expected.id().as_bytes());
+    assert_eq!(from_json.inputs().size(), 2);
+    assert_eq!(from_json.outputs().size(), 1);
+}
+
+#[wasm_bindgen_test]
+fn build_transaction_from_json_malformed() {
+    let fee_algorithm = Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into());
+    let build = |spec: &str| {
+        build_transaction_from_json(json(spec), &fee_algorithm, OutputPolicy::forget())
+    };
+    let address = "ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344";
+
+    // missing outputs
+    assert!(build(r#"{ "inputs": [] }"#).is_err());
+    // unknown input kind
+    assert!(build(&format!(
+        r#"{{ "inputs": [{{ "group": {{ "address": "{}", "value": "10" }} }}], "outputs": [] }}"#,
+        address
+    ))
+    .is_err());
+    // malformed fragment id
+    assert!(build(
+        r#"{ "inputs": [{ "utxo": { "fragment_id": "zz", "output_index": 0, "value": "10" } }], "outputs": [] }"#
+    )
+    .is_err());
+    // malformed value
+    assert!(build(&format!(
+        r#"{{ "inputs": [{{ "account": {{ "address": "{}", "value": "ten" }} }}], "outputs": [] }}"#,
+        address
+    ))
+    .is_err());
+    // not an account address
+    assert!(build(
+        r#"{ "inputs": [{ "account": { "address": "ta1sw6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgj44fgl3", "value": "10" } }], "outputs": [] }"#
+    )
+    .is_err());
+    // malformed certificate
+    assert!(build(&format!(
+        r#"{{ "inputs": [], "outputs": [{{ "address": "{}", "value": "10" }}], "certificate": "cert1" }}"#,
+        address
+    ))
+    .is_err());
+}
+
+#[wasm_bindgen_test]
+fn parse_bech32_each_prefix() {
+    let type_of = |s: &str| {
+        js_field(&parse_bech32(s).unwrap(), "type")
+            .as_string()
+            .unwrap()
+    };
+
+    assert_eq!(
+        type_of("ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2"),
+        "PublicKey"
+    );
+    assert_eq!(
+        type_of("ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje"),
+        "PrivateKey"
+    );
+    assert_eq!(type_of("ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef"), "PrivateKey");
+    assert_eq!(
+        type_of("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344"),
+        "Address"
+    );
+    assert_eq!(
+        type_of("ta1sw6gu33yw73dr60f2ehp6xemgf30r49rzc25gkrfnrfuuyf0mycgj44fgl3"),
+        "Address"
+    );
+
+    let stake_pool_id =
+        StakePoolId::from_hex("541db50349e2bc1a5b1a73939b9d86fc45067117cc930c36afbb6fb0a9329d41")
+            .unwrap();
+    let stake_key = PublicKey::from_bech32(
+        "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2",
+    )
+    .unwrap();
+    let certificate = Certificate::stake_delegation(stake_pool_id, stake_key);
+    assert_eq!(type_of(&certificate.to_bech32().unwrap()), "Certificate");
+
+    let finalizer = TransactionFinalizer::new(mock_builder(32, 20).unchecked_finalize());
+    let key = PrivateKey::from_bech32("ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef").unwrap();
+    let witness = Witness::for_utxo(Hash::from_bytes(&[0]), finalizer.get_txid(), key);
+    assert_eq!(type_of(&witness.to_bech32().unwrap()), "Witness");
+
+    // valid bech32 with a prefix unknown to this library
+    let error = parse_bech32("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw").unwrap_err();
+    assert!(error.as_string().unwrap().contains("Unknown bech32 prefix"));
+}
+
+#[wasm_bindgen_test]
+fn generated_transaction_size_breakdown() {
+    let signed = mock_generated_transaction(32, 20);
+    let transaction = signed.transaction();
+    let breakdown = signed.size_breakdown().unwrap();
+    let field = |key: &str| js_field(&breakdown, key).as_f64().unwrap() as usize;
+    assert_eq!(
+        field("inputs"),
+        transaction.inputs().get(0).as_bytes().unwrap().len()
+    );
+    assert_eq!(
+        field("outputs"),
+        transaction.outputs().get(0).as_bytes().unwrap().len()
+    );
+    assert!(field("witnesses") > 0);
+    assert_eq!(
+        field("inputs") + field("outputs") + field("witnesses") + field("overhead"),
+        signed.as_bytes().unwrap().len()
+    );
+}
+
+#[wasm_bindgen_test]
+fn generated_transaction_mempool_metrics() {
+    let signed = mock_generated_transaction(32, 20);
+    let size = signed.as_bytes().unwrap().len() as f64;
+    let metrics = signed
+        .mempool_metrics(&Fee::linear_fee(2u64.into(), 0u64.into(), 0u64.into()))
+        .unwrap();
+    assert_eq!(js_field(&metrics, "fee").as_string().unwrap(), "12");
+    assert_eq!(js_field(&metrics, "size").as_f64().unwrap(), size);
+    assert_eq!(
+        js_field(&metrics, "feePerByte").as_f64().unwrap(),
+        12.0 / size
+    );
+
+    assert_eq!(js_field(&metrics, "minimumFee").as_string().unwrap(), "2");
+
+    // the metrics of a transaction paying less than the minimum fee are given too
+    let expensive = Fee::linear_fee(20u64.into(), 0u64.into(), 0u64.into());
+    let metrics = signed.mempool_metrics(&expensive).unwrap();
+    assert_eq!(js_field(&metrics, "fee").as_string().unwrap(), "12");
+    assert_eq!(js_field(&metrics, "minimumFee").as_string().unwrap(), "20");
+}
+
+#[wasm_bindgen_test]
+fn block_certificate_counts() {
+    let stake_pool_id =
+        StakePoolId::from_hex("541db50349e2bc1a5b1a73939b9d86fc45067117cc930c36afbb6fb0a9329d41")
+            .unwrap();
+    let stake_key = PublicKey::from_bech32(
+        "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2",
+    )
+    .unwrap();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder
+        .set_certificate(Certificate::stake_delegation(stake_pool_id, stake_key))
+        .unwrap();
+    let mut finalizer = TransactionFinalizer::new(txbuilder.unchecked_finalize());
+    let key = PrivateKey::from_bech32("ed25519e_sk1lzkckzvwh7gn5f0krrmrxlpsywypu3kka2u82l3akm5gr8khra8suz6zv5jcwg8h6jy4pjs4dfvcrja07q9758xctp6cgkn5ykkgj9cts0mef").unwrap();
+    let witness = Witness::for_utxo(Hash::from_bytes(&[0]), finalizer.get_txid(), key);
+    finalizer.set_witness(0, witness).unwrap();
+    let delegation = Fragment::from_generated_transaction(finalizer.build().unwrap());
+
+    let block = mock_block(vec![mock_transaction_fragment(32, 20), delegation]);
+    let counts = block.certificate_counts().unwrap();
+    assert_eq!(js_field(&counts, "stakeDelegation").as_f64().unwrap(), 1.0);
+    assert_eq!(
+        js_field(&counts, "stakePoolRegistration").as_f64().unwrap(),
+        0.0
+    );
+    assert_eq!(
+        js_field(&counts, "stakePoolRetirement").as_f64().unwrap(),
+        0.0
+    );
+}
+
+#[wasm_bindgen_test]
+fn block_output_addresses() {
+    let block = mock_block(vec![
+        mock_transaction_fragment(32, 20),
+        mock_transaction_fragment(10, 5),
+    ]);
+    let addresses = block.output_addresses("ca").unwrap();
+    assert_eq!(addresses.len(), 1);
+    assert_eq!(
+        addresses[0].as_string().unwrap(),
+        "ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344"
+    );
+    assert!(mock_block(vec![])
+        .output_addresses("ca")
+        .unwrap()
+        .is_empty());
+}
+
+#[wasm_bindgen_test]
+fn transaction_likely_change_output() {
+    let change_address =
+        Address::from_string("ca1q5nr5pvt9e5p009strshxndrsx5etcentslp2rwj6csm8sfk24a2w3swacn")
+            .unwrap();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_output(change_address.clone(), 5u64.into());
+    let transaction = txbuilder.unchecked_finalize();
+
+    let mut my_addresses = Addresses::new();
+    my_addresses.add(change_address);
+    assert_eq!(transaction.likely_change_output(my_addresses), Some(1));
+    assert_eq!(transaction.likely_change_output(Addresses::new()), None);
+}
+
+#[wasm_bindgen_test]
+fn transaction_is_self_transfer() {
+    let payment_address =
+        Address::from_string("ca1qh9u0nxmnfg7af8ycuygx57p5xgzmnmgtaeer9xun7hly6mlgt3pj2xk344")
+            .unwrap();
+    let change_address =
+        Address::from_string("ca1q5nr5pvt9e5p009strshxndrsx5etcentslp2rwj6csm8sfk24a2w3swacn")
+            .unwrap();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_output(change_address.clone(), 5u64.into());
+    let transaction = txbuilder.unchecked_finalize();
+
+    let mut only_change = Addresses::new();
+    only_change.add(change_address.clone());
+    assert!(!transaction.is_self_transfer(only_change));
+
+    let mut both = Addresses::new();
+    both.add(change_address);
+    both.add(payment_address.clone());
+    assert!(transaction.is_self_transfer(both));
+
+    let mut payment = Addresses::new();
+    payment.add(payment_address);
+    assert!(!TransactionBuilder::new()
+        .unchecked_finalize()
+        .is_self_transfer(payment));
+}
+
+#[wasm_bindgen_test]
+fn witness_builder_matches_witness() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let finalizer = TransactionFinalizer::new(mock_builder(32, 20).unchecked_finalize());
+    let witness_builder = WitnessBuilder::new(Hash::from_bytes(&[0]));
+
+    let utxo =
+        witness_builder.for_utxo(finalizer.get_txid(), PrivateKey::from_bech32(key).unwrap());
+    let expected = Witness::for_utxo(
+        Hash::from_bytes(&[0]),
+        finalizer.get_txid(),
+        PrivateKey::from_bech32(key).unwrap(),
+    );
+    assert_eq!(utxo.as_bytes().unwrap(), expected.as_bytes().unwrap());
+
+    let account = witness_builder.for_account(
+        finalizer.get_txid(),
+        PrivateKey::from_bech32(key).unwrap(),
+        SpendingCounter::from_u32(3),
+    );
+    let expected = Witness::for_account(
+        Hash::from_bytes(&[0]),
+        finalizer.get_txid(),
+        PrivateKey::from_bech32(key).unwrap(),
+        SpendingCounter::from_u32(3),
+    );
+    assert_eq!(account.as_bytes().unwrap(), expected.as_bytes().unwrap());
+}
+
+#[wasm_bindgen_test]
+fn witness_for_account_checked() {
+    let key = "ed25519_sk17dvhvjmykpp2xg9arjrvun5fh4zglw63znrzu00hsseq5emruvsq2rzdje";
+    let finalizer = TransactionFinalizer::new(mock_builder(32, 20).unchecked_finalize());
+
+    let witness = Witness::for_account_checked(
+        Hash::from_bytes(&[0]),
+        finalizer.get_txid(),
+        PrivateKey::from_bech32(key).unwrap(),
+        SpendingCounter::from_u32(2),
+        SpendingCounter::from_u32(2),
+    )
+    .unwrap();
+    let expected = Witness::for_account(
+        Hash::from_bytes(&[0]),
+        finalizer.get_txid(),
+        PrivateKey::from_bech32(key).unwrap(),
+        SpendingCounter::from_u32(2),
+    );
+    assert_eq!(witness.as_bytes().unwrap(), expected.as_bytes().unwrap());
+
+    let error = Witness::for_account_checked(
+        Hash::from_bytes(&[0]),
+        finalizer.get_txid(),
+        PrivateKey::from_bech32(key).unwrap(),
+        SpendingCounter::from_u32(1),
+        SpendingCounter::from_u32(2),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        error.as_string().unwrap(),
+        "Invalid spending counter. Found 1, expected: 2"
+    );
+}
+
+#[wasm_bindgen_test]
+fn fragment_is_admissible() {
+    let fragment = mock_transaction_fragment(32, 20);
+    let size = fragment.as_bytes().unwrap().len();
+    assert!(fragment.is_admissible(size).unwrap());
+    assert!(!fragment.is_admissible(size - 1).unwrap());
+
+    let empty = TransactionFinalizer::new(TransactionBuilder::new().unchecked_finalize())
+        .build()
+        .unwrap();
+    assert!(!Fragment::from_generated_transaction(empty)
+        .is_admissible(size)
+        .unwrap());
+}
+
+#[wasm_bindgen_test]
+fn fragments_as_block_body_bytes() {
+    let first = mock_transaction_fragment(32, 20);
+    let second = mock_transaction_fragment(10, 5);
+    let mut expected = first.as_bytes().unwrap();
+    expected.extend(second.as_bytes().unwrap());
+
+    let block = mock_block(vec![first, second]);
+    assert_eq!(block.fragments().as_block_body_bytes().unwrap(), expected);
+}
+
+#[wasm_bindgen_test]
+fn block_for_each_fragment() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    let block = mock_block(vec![
+        mock_transaction_fragment(32, 20),
+        mock_transaction_fragment(10, 5),
+    ]);
+    let count = Rc::new(RefCell::new(0));
+    let counter = count.clone();
+    let callback = Closure::wrap(Box::new(move |_fragment: JsValue| {
+        *counter.borrow_mut() += 1;
+    }) as Box<dyn FnMut(JsValue)>);
+    block
+        .for_each_fragment(callback.as_ref().unchecked_ref())
+        .unwrap();
+    assert_eq!(*count.borrow(), 2);
+
+    let throwing = js_sys::Function::new_with_args("fragment", "throw new Error('stop')");
+    assert!(block.for_each_fragment(&throwing).is_err());
+}
+
+#[wasm_bindgen_test]
+fn transaction_spent_utxos() {
+    use wasm_bindgen::JsCast;
+
+    let account = Account::from_public_key(
+        PublicKey::from_bech32(
+            "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+        )
+        .unwrap(),
+    );
+    let mut txbuilder = TransactionBuilder::new();
+    txbuilder.add_input(Input::from_account(&account, 10u64.into()));
+    txbuilder.add_input(Input::from_utxo_parts(
+        FragmentId::from_bytes(&[1]),
+        3,
+        30u64.into(),
+    ));
+    let utxos = txbuilder.unchecked_finalize().spent_utxos();
+    assert_eq!(utxos.len(), 1);
+
+    // the pointers are UtxoPointer objects on the javascript side
+    let output_index: js_sys::Function = js_field(&utxos[0], "output_index").unchecked_into();
+    assert_eq!(
+        output_index.call0(&utxos[0]).unwrap().as_f64().unwrap(),
+        3.0
+    );
+}
+
+#[wasm_bindgen_test]
+fn transaction_spends_from_account() {
+    let account = Account::from_public_key(
+        PublicKey::from_bech32(
+            "ed25519_pk1kj8yvfrh5tg7n62kdcw3kw6zvtcafgckz4z9s6vc608pzt7exzys4s9gs8",
+        )
+        .unwrap(),
+    );
+    let other = Account::from_public_key(
+        PublicKey::from_bech32(
+            "ed25519_pk1dgaagyh470y66p899txcl3r0jaeaxu6yd7z2dxyk55qcycdml8gszkxze2",
+        )
+        .unwrap(),
+    );
+    assert!(!mock_builder(32, 20)
+        .unchecked_finalize()
+        .spends_from_account(&account));
+
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_input(Input::from_account(&account, 10u64.into()));
+    let transaction = txbuilder.unchecked_finalize();
+    assert!(transaction.spends_from_account(&account));
+    assert!(!transaction.spends_from_account(&other));
+}
+
+#[wasm_bindgen_test]
+fn certificate_pool_serial() {
+    let owner = PrivateKey::generate_ed25519().unwrap().to_public();
+    let registration = Certificate::stake_pool_registration(mock_stake_pool_info(owner.clone()));
+    assert_eq!(registration.pool_serial().unwrap().to_str(), "1010101010");
+
+    let stake_pool_id =
+        StakePoolId::from_hex("541db50349e2bc1a5b1a73939b9d86fc45067117cc930c36afbb6fb0a9329d41")
+            .unwrap();
+    let delegation = Certificate::stake_delegation(stake_pool_id, owner);
+    assert!(delegation.pool_serial().is_err());
+}
+
+#[wasm_bindgen_test]
+fn generated_transaction_clone_tx() {
+    let signed = mock_generated_transaction(32, 20);
+    let copy = signed.clone_tx();
+    let fragment = Fragment::from_generated_transaction(signed);
+    assert_eq!(copy.as_bytes().unwrap(), fragment.as_bytes().unwrap());
+    assert_eq!(copy.transaction().implied_fee().unwrap(), 12u64.into());
+}
+
+#[wasm_bindgen_test]
+fn generated_transaction_to_bech32() {
+    let signed = mock_generated_transaction(32, 20);
+    let bech32 = signed.to_bech32().unwrap();
+    assert!(bech32.starts_with("message1"));
+
+    let info = inspect_bech32(&bech32).unwrap();
+    assert_eq!(js_field(&info, "hrp").as_string().unwrap(), "message");
+    assert_eq!(
+        js_field(&info, "data_length").as_f64().unwrap() as usize,
+        signed.as_bytes().unwrap().len()
+    );
+}
+
+#[wasm_bindgen_test]
+fn output_is_account_address() {
+    let key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let delegation = PrivateKey::generate_ed25519().unwrap().to_public();
+    let output = |address| Output::new(address, 10u64.into());
+    assert!(output(Address::account_from_public_key(
+        key.clone(),
+        AddressDiscrimination::Test
+    ))
+    .is_account_address());
+    assert!(!output(Address::single_from_public_key(
+        key.clone(),
+        AddressDiscrimination::Test
+    ))
+    .is_account_address());
+    assert!(!output(Address::delegation_from_public_key(
+        key,
+        delegation,
+        AddressDiscrimination::Test
+    ))
+    .is_account_address());
+}
+
+#[wasm_bindgen_test]
+fn compute_pool_id_matches_registration() {
+    let owner = PrivateKey::generate_ed25519().unwrap().to_public();
+    let registered = mock_stake_pool_info(owner.clone());
+    let registered_id = registered.id().to_string();
+    let registration = Certificate::stake_pool_registration(registered);
+    assert_eq!(registration.get_type(), "StakePoolRegistration");
+
+    let info = mock_stake_pool_info(owner.clone());
+    let pool_id = compute_pool_id(
+        registration.pool_serial().unwrap(),
+        info.owners(),
+        info.kes_public_key(),
+        info.vrf_public_key(),
+    );
+    assert_eq!(pool_id.to_string(), registered_id);
+
+    let other_serial = compute_pool_id(
+        U128::from_str("1").unwrap(),
+        info.owners(),
+        info.kes_public_key(),
+        info.vrf_public_key(),
+    );
+    assert_ne!(other_serial.to_string(), registered_id);
+}
+
+#[wasm_bindgen_test]
+fn transaction_pays_to_ignoring_discrimination() {
+    let key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_output(
+        Address::single_from_public_key(key.clone(), AddressDiscrimination::Production),
+        5u64.into(),
+    );
+    let transaction = txbuilder.unchecked_finalize();
+
+    let test_address = Address::single_from_public_key(key, AddressDiscrimination::Test);
+    assert!(!transaction.pays_to(&test_address));
+    assert!(transaction.pays_to_ignoring_discrimination(&test_address));
+
+    let other_key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let other_address = Address::single_from_public_key(other_key, AddressDiscrimination::Test);
+    assert!(!transaction.pays_to_ignoring_discrimination(&other_address));
+}
+
+#[wasm_bindgen_test]
+fn transaction_finalizer_transaction() {
+    let transaction = mock_builder(32, 20).unchecked_finalize();
+    let finalizer = TransactionFinalizer::new(transaction.clone());
+
+    let inner = finalizer.transaction();
+    assert_eq!(inner.id().as_bytes(), transaction.id().as_bytes());
+    assert_eq!(inner.id().as_bytes(), finalizer.get_txid().as_bytes());
+    assert!(inner.canonical_equals(&transaction));
+    assert_eq!(inner.inputs().size(), 1);
+    assert_eq!(inner.outputs().get(0).value(), 20u64.into());
+}
+
+#[wasm_bindgen_test]
+fn stake_pool_info_owners() {
+    let first = PrivateKey::generate_ed25519().unwrap().to_public();
+    let second = PrivateKey::generate_ed25519().unwrap().to_public();
+    let mut owners = PublicKeys::new();
+    owners.add(first.clone());
+    owners.add(second.clone());
+    let info = StakePoolInfo::new(
+        U128::from_str("1").unwrap(),
+        owners,
+        KesPublicKey::from_bech32(
+            "kes25519-12-pk1s6pr56t6uzkmgdqs2krrh0tw9yyvdwfkzk7e90nsfxg08wqxg6qs9nuu5s",
+        )
+        .unwrap(),
+        VrfPublicKey::from_bech32(
+            "vrf_pk1fz79zuzm2k8agqs7a5fgdpzprgpnzh58jhz35wjftd0km8dagfdqs8e08k",
+        )
+        .unwrap(),
+    );
+
+    let owners = info.owners();
+    assert_eq!(owners.size(), 2);
+    assert_eq!(owners.get(0).as_bytes(), first.as_bytes());
+    assert_eq!(owners.get(1).as_bytes(), second.as_bytes());
+}
+
+#[wasm_bindgen_test]
+fn transaction_builder_from_transaction() {
+    let stake_pool_id =
+        StakePoolId::from_hex("541db50349e2bc1a5b1a73939b9d86fc45067117cc930c36afbb6fb0a9329d41")
+            .unwrap();
+    let stake_key = PrivateKey::generate_ed25519().unwrap().to_public();
+    let mut txbuilder = mock_builder(32, 20);
+    txbuilder.add_output(
+        Address::account_from_public_key(stake_key.clone(), AddressDiscrimination::Test),
+        5u64.into(),
+    );
+    txbuilder
+        .set_certificate(Certificate::stake_delegation(stake_pool_id, stake_key))
+        .unwrap();
+    let transaction = txbuilder.unchecked_finalize();
+
+    let rebuilt = TransactionBuilder::from_transaction(transaction.clone());
+    assert_eq!(rebuilt.get_inputs().size(), 1);
+    assert!(rebuilt
+        .get_inputs()
+        .get(0)
+        .equals(&transaction.inputs().get(0)));
+    let outputs = rebuilt.get_outputs();
+    assert_eq!(outputs.size(), 2);
+    assert_eq!(outputs.get(0).value(), 20u64.into());
+    assert_eq!(outputs.get(1).value(), 5u64.into());
+
+    let rebuilt = rebuilt.unchecked_finalize();
+    assert_eq!(rebuilt.certificate_count(), 1);
+    assert!(rebuilt.canonical_equals(&transaction));
+    assert_eq!(rebuilt.id().as_bytes(), transaction.id().as_bytes());
+}